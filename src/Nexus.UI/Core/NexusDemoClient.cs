@@ -75,7 +75,7 @@ public class CatalogsDemoClient : ICatalogsClient
             var resource1 = new Resource(
                 Id: "temperature",
                 Properties: properties1,
-                Representations: new List<Representation>() { new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1), default) }
+                Representations: new List<Representation>() { new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1), default, default, RepresentationKind.Original) }
             );
 
             var properties2 = new Dictionary<string, JsonElement>()
@@ -88,7 +88,7 @@ public class CatalogsDemoClient : ICatalogsClient
             var resource2 = new Resource(
                 Id: "wind_speed",
                 Properties: properties2,
-                Representations: new List<Representation>() { new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1), default) }
+                Representations: new List<Representation>() { new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1), default, default, RepresentationKind.Original) }
             );
 
             var resources = new List<Resource>() { resource1, resource2 };
@@ -96,7 +96,8 @@ public class CatalogsDemoClient : ICatalogsClient
             var catalog = new ResourceCatalog(
                 Id: "/SAMPLE/LOCAL",
                 Properties: default,
-                Resources: resources
+                Resources: resources,
+                ChildIds: default
             );
 
             return Task.FromResult(catalog);
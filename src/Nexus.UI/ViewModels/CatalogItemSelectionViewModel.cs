@@ -43,7 +43,9 @@ public class CatalogItemSelectionViewModel(
         if (parameters is null || !parameters.Any())
             return default;
 
-        var serializedParameters = parameters.Select(parameter => $"{parameter.Key}={parameter.Value}");
+        var serializedParameters = parameters
+            .OrderBy(parameter => parameter.Key, StringComparer.Ordinal)
+            .Select(parameter => $"{parameter.Key}={parameter.Value}");
         var parametersString = $"({string.Join(',', serializedParameters)})";
 
         return parametersString;
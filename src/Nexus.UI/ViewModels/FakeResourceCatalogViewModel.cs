@@ -20,7 +20,7 @@ public class FakeResourceCatalogViewModel : ResourceCatalogViewModel
         }
 
         ChildrenTask = new Lazy<Task<List<ResourceCatalogViewModel>>>(func);
-        CatalogTask = new Lazy<Task<ResourceCatalog>>(() => Task.FromResult(new ResourceCatalog(id, default, default)));
+        CatalogTask = new Lazy<Task<ResourceCatalog>>(() => Task.FromResult(new ResourceCatalog(id, default, default, default)));
         LicenseTask = new Lazy<Task<string?>>(() => Task.FromResult(default(string?)));
         TimeRangeTask = new Lazy<Task<CatalogTimeRange>>(() => Task.FromResult(new CatalogTimeRange(default, default)));
     }
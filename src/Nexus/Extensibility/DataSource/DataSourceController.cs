@@ -88,10 +88,14 @@ internal class DataSourceController(
     {
         _catalogCache = catalogCache;
 
-        var clonedSourceConfiguration = DataSourceRegistration.Configuration is null
+        IReadOnlyDictionary<string, JsonElement>? clonedSourceConfiguration = DataSourceRegistration.Configuration is null
             ? default
             : DataSourceRegistration.Configuration.ToDictionary(entry => entry.Key, entry => entry.Value.Clone());
 
+        /* the upgrade is applied on every initialization, i.e. the stored registration is left untouched */
+        if (DataSource is IVersionedConfiguration versionedConfiguration)
+            clonedSourceConfiguration = ExtensibilityUtilities.UpgradeSourceConfiguration(versionedConfiguration, clonedSourceConfiguration);
+
         var context = new DataSourceContext(
             ResourceLocator: DataSourceRegistration.ResourceLocator,
             SystemConfiguration: SystemConfiguration,
@@ -380,6 +384,7 @@ internal class DataSourceController(
             *  - Read cached data into readUnit.DataWriter
             *  - Read remaining data into readUnit.ReadRequest
             *  - Process readUnit.ReadRequest data and copy result into readUnit.DataWriter
+            *  - Aggregated representations are read directly (but still cached) if the data source is able to resample them itself
             */
         var processingReadUnits = readUnits
             .Where(readUnit => readUnit.CatalogItemRequest.BaseItem is not null)
@@ -431,6 +436,14 @@ internal class DataSourceController(
         await NexusUtilities.WhenAllFailFastAsync(readingTasks, cancellationToken);
     }
 
+    private bool CanReadAggregatedDirectly(ReadUnit readUnit)
+    {
+        var item = readUnit.CatalogItemRequest.Item;
+
+        return item.Representation.Kind != RepresentationKind.Resampled &&
+            DataSource.CanResample(item.Catalog.Id, item.Resource.Id);
+    }
+
     private async Task ReadOriginalAsync(
         DateTime begin,
         DateTime end,
@@ -528,7 +541,11 @@ internal class DataSourceController(
         var item = readUnit.CatalogItemRequest.Item;
         var baseItem = readUnit.CatalogItemRequest.BaseItem!;
         var samplePeriod = item.Representation.SamplePeriod;
-        var baseSamplePeriod = baseItem.Representation.SamplePeriod;
+
+        /* the data source either provides the aggregated representation itself or the base representation is aggregated here */
+        var readDirectly = CanReadAggregatedDirectly(readUnit);
+        var readItem = readDirectly ? item : baseItem;
+        var readSamplePeriod = readItem.Representation.SamplePeriod;
 
         /* target buffer */
         var buffer = readUnit.DataWriter
@@ -537,9 +554,9 @@ internal class DataSourceController(
         var targetBuffer = new CastMemoryManager<byte, double>(buffer).Memory;
 
         /* read request */
-        var readElementCount = ExtensibilityUtilities.CalculateElementCount(begin, end, baseSamplePeriod);
+        var readElementCount = ExtensibilityUtilities.CalculateElementCount(begin, end, readSamplePeriod);
 
-        using var readRequestManager = new ReadRequestManager(baseItem, readElementCount);
+        using var readRequestManager = new ReadRequestManager(readItem, readElementCount);
         var readRequest = readRequestManager.Request;
 
         /* go */
@@ -569,8 +586,8 @@ internal class DataSourceController(
             /* load and process remaining data from source */
             Logger.LogTrace("Load and process {PeriodCount} uncached periods from source", uncachedIntervals.Count);
 
-            var elementSize = baseItem.Representation.ElementSize;
-            var sourceSamplePeriod = baseSamplePeriod;
+            var elementSize = readItem.Representation.ElementSize;
+            var sourceSamplePeriod = readSamplePeriod;
             var targetSamplePeriod = samplePeriod;
 
             var blockSize = item.Representation.Kind == RepresentationKind.Resampled
@@ -607,13 +624,25 @@ internal class DataSourceController(
                     start: NexusUtilities.Scale(offset, targetSamplePeriod),
                     length: NexusUtilities.Scale(length, targetSamplePeriod));
 
-                _processingService.Aggregate(
-                    baseItem.Representation.DataType,
-                    item.Representation.Kind,
-                    slicedReadRequest.Data,
-                    slicedReadRequest.Status,
-                    targetBuffer: slicedTargetBuffer,
-                    blockSize);
+                if (readDirectly)
+                {
+                    BufferUtilities.ApplyRepresentationStatusByDataType(
+                        item.Representation.DataType,
+                        slicedReadRequest.Data,
+                        slicedReadRequest.Status,
+                        target: slicedTargetBuffer);
+                }
+
+                else
+                {
+                    _processingService.Aggregate(
+                        baseItem.Representation.DataType,
+                        item.Representation.Kind,
+                        slicedReadRequest.Data,
+                        slicedReadRequest.Status,
+                        targetBuffer: slicedTargetBuffer,
+                        blockSize);
+                }
             }
 
             /* update cache */
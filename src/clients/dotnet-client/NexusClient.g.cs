@@ -2853,7 +2853,8 @@ public record CatalogItem(ResourceCatalog Catalog, Resource Resource, Representa
 /// <param name="Id">Gets the identifier.</param>
 /// <param name="Properties">Gets the properties.</param>
 /// <param name="Resources">Gets the list of representations.</param>
-public record ResourceCatalog(string Id, IReadOnlyDictionary<string, JsonElement>? Properties, IReadOnlyList<Resource>? Resources);
+/// <param name="ChildIds">Gets the list of child catalog identifiers.</param>
+public record ResourceCatalog(string Id, IReadOnlyDictionary<string, JsonElement>? Properties, IReadOnlyList<Resource>? Resources, IReadOnlyList<string>? ChildIds);
 
 /// <summary>
 /// A resource is part of a resource catalog and holds a list of representations.
@@ -2869,7 +2870,9 @@ public record Resource(string Id, IReadOnlyDictionary<string, JsonElement>? Prop
 /// <param name="DataType">The data type.</param>
 /// <param name="SamplePeriod">The sample period.</param>
 /// <param name="Parameters">The optional list of parameters.</param>
-public record Representation(NexusDataType DataType, TimeSpan SamplePeriod, IReadOnlyDictionary<string, JsonElement>? Parameters);
+/// <param name="Properties">The optional properties.</param>
+/// <param name="Kind">The representation kind.</param>
+public record Representation(NexusDataType DataType, TimeSpan SamplePeriod, IReadOnlyDictionary<string, JsonElement>? Parameters, IReadOnlyDictionary<string, JsonElement>? Properties, RepresentationKind Kind);
 
 /// <summary>
 /// Specifies the Nexus data type.
@@ -2928,6 +2931,68 @@ public enum NexusDataType
 }
 
 
+/// <summary>
+/// Specifies the kind of a representation, i.e. whether it contains original data or how it has been derived from it.
+/// </summary>
+public enum RepresentationKind
+{
+    /// <summary>
+    /// Original
+    /// </summary>
+    Original,
+
+    /// <summary>
+    /// Resampled
+    /// </summary>
+    Resampled,
+
+    /// <summary>
+    /// Mean
+    /// </summary>
+    Mean,
+
+    /// <summary>
+    /// MeanPolarDeg
+    /// </summary>
+    MeanPolarDeg,
+
+    /// <summary>
+    /// Min
+    /// </summary>
+    Min,
+
+    /// <summary>
+    /// Max
+    /// </summary>
+    Max,
+
+    /// <summary>
+    /// Std
+    /// </summary>
+    Std,
+
+    /// <summary>
+    /// Rms
+    /// </summary>
+    Rms,
+
+    /// <summary>
+    /// MinBitwise
+    /// </summary>
+    MinBitwise,
+
+    /// <summary>
+    /// MaxBitwise
+    /// </summary>
+    MaxBitwise,
+
+    /// <summary>
+    /// Sum
+    /// </summary>
+    Sum
+}
+
+
 /// <summary>
 /// A structure for catalog information.
 /// </summary>
@@ -294,6 +294,7 @@ class ResourceCatalog:
         id: Gets the identifier.
         properties: Gets the properties.
         resources: Gets the list of representations.
+        child_ids: Gets the list of child catalog identifiers.
     """
 
     id: str
@@ -305,6 +306,9 @@ class ResourceCatalog:
     resources: Optional[list[Resource]]
     """Gets the list of representations."""
 
+    child_ids: Optional[list[str]]
+    """Gets the list of child catalog identifiers."""
+
 
 @dataclass(frozen=True)
 class Resource:
@@ -336,6 +340,8 @@ class Representation:
         data_type: The data type.
         sample_period: The sample period.
         parameters: The optional list of parameters.
+        properties: The optional properties.
+        kind: The representation kind.
     """
 
     data_type: NexusDataType
@@ -347,6 +353,12 @@ class Representation:
     parameters: Optional[dict[str, object]]
     """The optional list of parameters."""
 
+    properties: Optional[dict[str, object]]
+    """The optional properties."""
+
+    kind: RepresentationKind
+    """The representation kind."""
+
 
 class NexusDataType(Enum):
     """Specifies the Nexus data type."""
@@ -382,6 +394,43 @@ class NexusDataType(Enum):
     """FLOAT64"""
 
 
+class RepresentationKind(Enum):
+    """Specifies the kind of a representation, i.e. whether it contains original data or how it has been derived from it."""
+
+    ORIGINAL = "ORIGINAL"
+    """Original"""
+
+    RESAMPLED = "RESAMPLED"
+    """Resampled"""
+
+    MEAN = "MEAN"
+    """Mean"""
+
+    MEAN_POLAR_DEG = "MEAN_POLAR_DEG"
+    """MeanPolarDeg"""
+
+    MIN = "MIN"
+    """Min"""
+
+    MAX = "MAX"
+    """Max"""
+
+    STD = "STD"
+    """Std"""
+
+    RMS = "RMS"
+    """Rms"""
+
+    MIN_BITWISE = "MIN_BITWISE"
+    """MinBitwise"""
+
+    MAX_BITWISE = "MAX_BITWISE"
+    """MaxBitwise"""
+
+    SUM = "SUM"
+    """Sum"""
+
+
 @dataclass(frozen=True)
 class CatalogInfo:
     """
@@ -1,6 +1,9 @@
 ﻿// MIT License
 // Copyright (c) [2024] [nexus-main]
 
+using System.Buffers.Binary;
+using System.Globalization;
+using System.Text.Json;
 using System.Text.Json.Nodes;
 using System.Text.RegularExpressions;
 
@@ -33,16 +36,76 @@ public static partial class DataModelExtensions
     /// </summary>
     public const string WarningKey = "warning";
 
+    /// <summary>
+    /// A constant with the key for a writable property.
+    /// </summary>
+    public const string WritableKey = "writable";
+
+    /// <summary>
+    /// A constant with the key for a catalog schema version property. It specifies which version of the well-known catalog properties the catalog conforms to (see <see cref="ResourceCatalog.CurrentSchemaVersion"/>).
+    /// </summary>
+    public const string SchemaVersionKey = "catalog-schema-version";
+
     /// <summary>
     /// A constant with the key for a unit property.
     /// </summary>
     public const string UnitKey = "unit";
 
+    /// <summary>
+    /// A constant with the key for a unit name property.
+    /// </summary>
+    public const string UnitNameKey = "unit-name";
+
     /// <summary>
     /// A constant with the key for a groups property.
     /// </summary>
     public const string GroupsKey = "groups";
 
+    /// <summary>
+    /// A constant with the key for a counter property.
+    /// </summary>
+    public const string CounterKey = "counter";
+
+    /// <summary>
+    /// A constant with the key for a minimum value property.
+    /// </summary>
+    public const string MinKey = "min";
+
+    /// <summary>
+    /// A constant with the key for a maximum value property.
+    /// </summary>
+    public const string MaxKey = "max";
+
+    /// <summary>
+    /// A constant with the key for a scale property of fixed-point representations.
+    /// </summary>
+    public const string ScaleKey = "scale";
+
+    /// <summary>
+    /// A constant with the key for an offset property of fixed-point representations.
+    /// </summary>
+    public const string OffsetKey = "offset";
+
+    /// <summary>
+    /// A constant with the key for an interpolation property.
+    /// </summary>
+    public const string InterpolationKey = "interpolation";
+
+    /// <summary>
+    /// A constant with the key for an enumeration map property. Its value maps the integer values of a categorical representation to labels, e.g. <c>0=off,1=on,2=fault</c>.
+    /// </summary>
+    public const string EnumMapKey = "enum-map";
+
+    /// <summary>
+    /// A constant with the key for a sensor property. Its value is an object with the optional members <c>serial</c>, <c>calibrated-at</c> and <c>location</c>.
+    /// </summary>
+    public const string SensorKey = "sensor";
+
+    /// <summary>
+    /// A constant with the key for a default representation property.
+    /// </summary>
+    public const string DefaultRepresentationKey = "default-representation";
+
     internal const string BasePathKey = "base-path";
 
     /// <summary>
@@ -67,6 +130,44 @@ public static partial class DataModelExtensions
         return catalogBuilder.WithProperty(LicenseKey, license);
     }
 
+    /// <summary>
+    /// Declares whether writing to the catalog is permitted.
+    /// </summary>
+    /// <param name="catalogBuilder">The catalog builder.</param>
+    /// <param name="isWritable">A boolean which indicates if writing is permitted.</param>
+    /// <returns>A resource catalog builder.</returns>
+    public static ResourceCatalogBuilder WithWritable(this ResourceCatalogBuilder catalogBuilder, bool isWritable)
+    {
+        return catalogBuilder.WithProperty(WritableKey, isWritable);
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if writing to the catalog is permitted. By default, a catalog is read-only.
+    /// </summary>
+    /// <param name="catalog">The catalog.</param>
+    /// <returns>A boolean which indicates if writing is permitted.</returns>
+    public static bool IsWritable(this ResourceCatalog catalog)
+    {
+        return catalog.Properties is not null &&
+            catalog.Properties.TryGetValue(WritableKey, out var element) &&
+            element.ValueKind == JsonValueKind.True;
+    }
+
+    /// <summary>
+    /// Gets the version of the well-known catalog properties the catalog conforms to.
+    /// </summary>
+    /// <param name="catalog">The catalog.</param>
+    /// <returns>The schema version or <see langword="null"/> if none has been defined.</returns>
+    public static int? GetSchemaVersion(this ResourceCatalog catalog)
+    {
+        return catalog.Properties is not null &&
+            catalog.Properties.TryGetValue(SchemaVersionKey, out var element) &&
+            element.ValueKind == JsonValueKind.Number &&
+            element.TryGetInt32(out var version)
+                ? version
+                : default;
+    }
+
     /// <summary>
     /// Adds a unit.
     /// </summary>
@@ -78,6 +179,27 @@ public static partial class DataModelExtensions
         return resourceBuilder.WithProperty(UnitKey, unit);
     }
 
+    /// <summary>
+    /// Adds a descriptive unit name (e.g. <c>degrees Celsius</c>) in addition to the unit symbol (e.g. <c>°C</c>).
+    /// </summary>
+    /// <param name="resourceBuilder">The resource builder.</param>
+    /// <param name="unitName">The unit name to add.</param>
+    /// <returns>A resource builder.</returns>
+    public static ResourceBuilder WithUnitName(this ResourceBuilder resourceBuilder, string unitName)
+    {
+        return resourceBuilder.WithProperty(UnitNameKey, unitName);
+    }
+
+    /// <summary>
+    /// Gets the descriptive unit name of a resource.
+    /// </summary>
+    /// <param name="resource">The resource.</param>
+    /// <returns>The unit name or <see langword="null"/> if none has been defined.</returns>
+    public static string? GetUnitName(this Resource resource)
+    {
+        return resource.Properties?.GetStringValue(UnitNameKey);
+    }
+
     /// <summary>
     /// Adds a description.
     /// </summary>
@@ -104,11 +226,539 @@ public static partial class DataModelExtensions
     /// Adds groups.
     /// </summary>
     /// <param name="resourceBuilder">The resource builder.</param>
-    /// <param name="groups">The groups to add.</param>
+    /// <param name="groups">The groups to add. Each group is trimmed, empty groups are dropped and duplicates are removed.</param>
     /// <returns>A resource builder.</returns>
     public static ResourceBuilder WithGroups(this ResourceBuilder resourceBuilder, params string[] groups)
     {
-        return resourceBuilder.WithProperty(GroupsKey, new JsonArray(groups.Select(group => (JsonNode)group!).ToArray()));
+        var normalizedGroups = DataModelUtilities.NormalizeGroups(groups);
+
+        return resourceBuilder.WithProperty(GroupsKey, new JsonArray(normalizedGroups.Select(group => (JsonNode)group!).ToArray()));
+    }
+
+    /// <summary>
+    /// Marks the resource as a counter (e.g. an energy meter) which wraps to zero when it reaches <paramref name="max"/>.
+    /// </summary>
+    /// <param name="resourceBuilder">The resource builder.</param>
+    /// <param name="max">The value at which the counter wraps to zero.</param>
+    /// <returns>A resource builder.</returns>
+    public static ResourceBuilder WithCounter(this ResourceBuilder resourceBuilder, double max)
+    {
+        return resourceBuilder.WithProperty(CounterKey, new JsonObject() { ["max"] = max });
+    }
+
+    /// <summary>
+    /// Adds the expected value range (e.g. the physical bounds of an instrument).
+    /// </summary>
+    /// <param name="resourceBuilder">The resource builder.</param>
+    /// <param name="min">The minimum value.</param>
+    /// <param name="max">The maximum value.</param>
+    /// <returns>A resource builder.</returns>
+    /// <exception cref="ArgumentException">Thrown when <paramref name="min"/> is not less than <paramref name="max"/>.</exception>
+    public static ResourceBuilder WithRange(this ResourceBuilder resourceBuilder, double min, double max)
+    {
+        if (!(min < max))
+            throw new ArgumentException($"The minimum value {min} must be less than the maximum value {max}.");
+
+        return resourceBuilder
+            .WithProperty(MinKey, min)
+            .WithProperty(MaxKey, max);
+    }
+
+    /// <summary>
+    /// Gets the expected value range of a resource.
+    /// </summary>
+    /// <param name="resource">The resource.</param>
+    /// <returns>The expected value range or <see langword="null"/> if the resource has no valid range.</returns>
+    public static (double Min, double Max)? GetRange(this Resource resource)
+    {
+        if (resource.Properties is null ||
+            !resource.Properties.TryGetValue(MinKey, out var minElement) ||
+            !resource.Properties.TryGetValue(MaxKey, out var maxElement) ||
+            minElement.ValueKind != JsonValueKind.Number ||
+            maxElement.ValueKind != JsonValueKind.Number)
+            return default;
+
+        var min = minElement.GetDouble();
+        var max = maxElement.GetDouble();
+
+        if (!(min < max))
+            return default;
+
+        return (min, max);
+    }
+
+    /// <summary>
+    /// Adds metadata about the physical sensor.
+    /// </summary>
+    /// <param name="resourceBuilder">The resource builder.</param>
+    /// <param name="sensorMetadata">The sensor metadata.</param>
+    /// <returns>A resource builder.</returns>
+    public static ResourceBuilder WithSensorMetadata(this ResourceBuilder resourceBuilder, SensorMetadata sensorMetadata)
+    {
+        var sensor = new Dictionary<string, object>();
+
+        if (sensorMetadata.Serial is not null)
+            sensor["serial"] = sensorMetadata.Serial;
+
+        if (sensorMetadata.CalibratedAt is not null)
+            sensor["calibrated-at"] = sensorMetadata.CalibratedAt.Value.ToUniversalTime().ToString("o", CultureInfo.InvariantCulture);
+
+        if (sensorMetadata.Location is not null)
+            sensor["location"] = sensorMetadata.Location;
+
+        return resourceBuilder.WithProperty(SensorKey, sensor);
+    }
+
+    /// <summary>
+    /// Gets the metadata about the physical sensor.
+    /// </summary>
+    /// <param name="resource">The resource.</param>
+    /// <returns>The sensor metadata or <see langword="null"/> if none has been defined.</returns>
+    public static SensorMetadata? GetSensorMetadata(this Resource resource)
+    {
+        if (resource.Properties is null ||
+            !resource.Properties.TryGetValue(SensorKey, out var sensor) ||
+            sensor.ValueKind != JsonValueKind.Object)
+            return default;
+
+        var calibratedAtString = resource.Properties.GetStringValue($"{SensorKey}/calibrated-at");
+
+        DateTime? calibratedAt = DateTime.TryParse(calibratedAtString, CultureInfo.InvariantCulture, DateTimeStyles.AdjustToUniversal | DateTimeStyles.AssumeUniversal, out var parsedCalibratedAt)
+            ? parsedCalibratedAt
+            : default;
+
+        return new SensorMetadata(
+            Serial: resource.Properties.GetStringValue($"{SensorKey}/serial"),
+            CalibratedAt: calibratedAt,
+            Location: resource.Properties.GetStringValue($"{SensorKey}/location"));
+    }
+
+    /// <summary>
+    /// Adds the representation to show by default. The representation must be added to the resource before it is built.
+    /// </summary>
+    /// <param name="resourceBuilder">The resource builder.</param>
+    /// <param name="representation">The default representation.</param>
+    /// <returns>A resource builder.</returns>
+    public static ResourceBuilder WithDefaultRepresentation(this ResourceBuilder resourceBuilder, Representation representation)
+    {
+        return resourceBuilder.WithProperty(DefaultRepresentationKey, representation.Id);
+    }
+
+    /// <summary>
+    /// Gets the representation to show by default.
+    /// </summary>
+    /// <param name="resource">The resource.</param>
+    /// <returns>The default representation or <see langword="null"/> if none has been defined.</returns>
+    /// <exception cref="Exception">Thrown when the default representation does not exist.</exception>
+    public static Representation? GetDefaultRepresentation(this Resource resource)
+    {
+        var representationId = resource.Properties?.GetStringValue(DefaultRepresentationKey);
+
+        if (representationId is null)
+            return default;
+
+        var representation = resource.Representations?.FirstOrDefault(current => current.Id == representationId);
+
+        return representation ?? throw new Exception($"The default representation {representationId} of resource {resource.Id} does not exist.");
+    }
+
+    /// <summary>
+    /// Gets the normalized groups of a resource.
+    /// </summary>
+    /// <param name="resource">The resource.</param>
+    /// <returns>The trimmed, non-empty and distinct groups.</returns>
+    public static string[] GetGroups(this Resource resource)
+    {
+        var groups = resource.Properties?.GetStringArray(GroupsKey);
+
+        if (groups is null)
+            return [];
+
+        return DataModelUtilities.NormalizeGroups(groups);
+    }
+
+    /// <summary>
+    /// Adds a property.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <param name="key">The key of the property.</param>
+    /// <param name="value">The value of the property.</param>
+    /// <returns>A new representation with the property added.</returns>
+    public static Representation WithProperty(this Representation representation, string key, object value)
+    {
+        var properties = representation.Properties is null
+            ? new Dictionary<string, JsonElement>()
+            : representation.Properties.ToDictionary(entry => entry.Key, entry => entry.Value);
+
+        properties[key] = JsonSerializer.SerializeToElement(value);
+
+        return representation with { Properties = properties };
+    }
+
+    /// <summary>
+    /// Adds a description.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <param name="description">The description to add.</param>
+    /// <returns>A new representation with the description added.</returns>
+    public static Representation WithDescription(this Representation representation, string description)
+    {
+        return representation.WithProperty(DescriptionKey, description);
+    }
+
+    /// <summary>
+    /// Adds the interpolation mode which specifies how values between two samples should be interpreted.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <param name="interpolationMode">The interpolation mode.</param>
+    /// <returns>A new representation with the interpolation mode added.</returns>
+    public static Representation WithInterpolation(this Representation representation, InterpolationMode interpolationMode)
+    {
+        return representation.WithProperty(InterpolationKey, interpolationMode.ToString().ToLowerInvariant());
+    }
+
+    /// <summary>
+    /// Gets the interpolation mode of a representation.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <returns>The interpolation mode or <see cref="InterpolationMode.None"/> if none has been defined.</returns>
+    public static InterpolationMode GetInterpolation(this Representation representation)
+    {
+        var interpolationString = representation.Properties?.GetStringValue(InterpolationKey);
+
+        return Enum.TryParse<InterpolationMode>(interpolationString, ignoreCase: true, out var interpolationMode)
+            ? interpolationMode
+            : InterpolationMode.None;
+    }
+
+    /// <summary>
+    /// Adds an enumeration map which maps the integer values of a categorical representation to labels.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <param name="enumMap">The enumeration map.</param>
+    /// <returns>A new representation with the enumeration map added.</returns>
+    /// <exception cref="ArgumentException">Thrown when the enumeration map is empty or when a label is blank, has leading or trailing whitespace or contains a reserved character (<c>,</c> or <c>=</c>).</exception>
+    public static Representation WithEnumMap(this Representation representation, IReadOnlyDictionary<long, string> enumMap)
+    {
+        if (enumMap.Count == 0)
+            throw new ArgumentException("The enumeration map must not be empty.");
+
+        foreach (var label in enumMap.Values)
+        {
+            if (string.IsNullOrWhiteSpace(label) || label.Trim() != label || label.IndexOfAny([',', '=']) != -1)
+                throw new ArgumentException($"The enumeration map label {label} is not valid.");
+        }
+
+        var enumMapString = string.Join(',', enumMap
+            .OrderBy(entry => entry.Key)
+            .Select(entry => $"{entry.Key.ToString(CultureInfo.InvariantCulture)}={entry.Value}"));
+
+        return representation.WithProperty(EnumMapKey, enumMapString);
+    }
+
+    /// <summary>
+    /// Gets the enumeration map which maps the integer values of a categorical representation to labels.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <returns>The enumeration map or <see langword="null"/> if none has been defined.</returns>
+    /// <exception cref="Exception">Thrown when the enumeration map is malformed or when an integer value is mapped more than once.</exception>
+    public static IReadOnlyDictionary<long, string>? GetEnumMap(this Representation representation)
+    {
+        var enumMapString = representation.Properties?.GetStringValue(EnumMapKey);
+
+        if (enumMapString is null)
+            return default;
+
+        var enumMap = new Dictionary<long, string>();
+
+        foreach (var entry in enumMapString.Split(','))
+        {
+            var parts = entry.Split('=', count: 2);
+
+            if (parts.Length != 2 ||
+                !long.TryParse(parts[0].Trim(), NumberStyles.AllowLeadingSign, CultureInfo.InvariantCulture, out var value) ||
+                string.IsNullOrWhiteSpace(parts[1]))
+                throw new Exception($"The enumeration map entry {entry} of representation {representation.Id} is malformed.");
+
+            if (!enumMap.TryAdd(value, parts[1].Trim()))
+                throw new Exception($"The value {value} is mapped more than once in the enumeration map of representation {representation.Id}.");
+        }
+
+        return enumMap;
+    }
+
+    /// <summary>
+    /// Adds a scale and an offset to a representation whose raw integer values are stored as <c>raw = (value - offset) / scale</c>.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <param name="scale">The scale.</param>
+    /// <param name="offset">The offset.</param>
+    /// <returns>A new representation with the scale and offset added.</returns>
+    public static Representation WithScaling(this Representation representation, double scale, double offset)
+    {
+        return representation
+            .WithProperty(ScaleKey, scale)
+            .WithProperty(OffsetKey, offset);
+    }
+
+    /// <summary>
+    /// Gets the scale and offset of a representation.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <returns>The scale and offset or <see langword="null"/> if none have been defined. A missing scale defaults to 1 and a missing offset to 0.</returns>
+    public static (double Scale, double Offset)? GetScaling(this Representation representation)
+    {
+        if (representation.Properties is null)
+            return default;
+
+        var hasScale = representation.Properties.TryGetValue(ScaleKey, out var scaleElement) && scaleElement.ValueKind == JsonValueKind.Number;
+        var hasOffset = representation.Properties.TryGetValue(OffsetKey, out var offsetElement) && offsetElement.ValueKind == JsonValueKind.Number;
+
+        if (!hasScale && !hasOffset)
+            return default;
+
+        return (
+            hasScale ? scaleElement.GetDouble() : 1.0,
+            hasOffset ? offsetElement.GetDouble() : 0.0
+        );
+    }
+
+    /// <summary>
+    /// Removes consecutive identical representations, i.e. adjacent representations with the same data type, sample period, kind, parameters and properties. Only the first occurrence is kept.
+    /// </summary>
+    /// <param name="representations">The representations.</param>
+    /// <returns>The list of representations without consecutive duplicates.</returns>
+    /// <exception cref="Exception">Thrown when two representations have the same identifier but are not consecutive identical duplicates, e.g. because their definitions or properties differ.</exception>
+    public static IReadOnlyList<Representation> DeduplicateIdentical(this IEnumerable<Representation> representations)
+    {
+        var result = new List<Representation>();
+        var ids = new HashSet<string>();
+
+        foreach (var representation in representations)
+        {
+            var previous = result.Count > 0
+                ? result[^1]
+                : default;
+
+            if (previous is not null &&
+                previous.Id == representation.Id &&
+                DataModelUtilities.AreIdentical(previous, representation) &&
+                DataModelUtilities.HaveIdenticalProperties(previous, representation))
+                continue;
+
+            if (!ids.Add(representation.Id))
+                throw new Exception($"The representation {representation.Id} is provided multiple times but the occurrences are not consecutive identical duplicates.");
+
+            result.Add(representation);
+        }
+
+        return result;
+    }
+
+    #endregion
+
+    #region NexusDataType
+
+    /// <summary>
+    /// Gets the number of bytes of a single value of the data type.
+    /// </summary>
+    /// <param name="dataType">The data type.</param>
+    /// <returns>The number of bytes.</returns>
+    public static int GetElementSize(this NexusDataType dataType)
+    {
+        return ((int)dataType & 0xFF) >> 3;
+    }
+
+    /// <summary>
+    /// Converts a raw discriminant (e.g. <c>0x340</c>) into a data type.
+    /// </summary>
+    /// <param name="value">The raw discriminant.</param>
+    /// <param name="dataType">The data type if the discriminant is known.</param>
+    /// <returns>A boolean which indicates if the discriminant is known.</returns>
+    public static bool TryToNexusDataType(ushort value, out NexusDataType dataType)
+    {
+        dataType = (NexusDataType)value;
+
+        if (Enum.IsDefined(dataType))
+            return true;
+
+        dataType = default;
+        return false;
+    }
+
+    /// <summary>
+    /// Converts a data type name (e.g. <c>FLOAT64</c> or <c>uint8</c>) into a data type. The comparison is case-insensitive and numeric strings are not accepted. Use <see cref="Enum.ToString()"/> to get the canonical uppercase name.
+    /// </summary>
+    /// <param name="name">The data type name.</param>
+    /// <param name="dataType">The data type if the name is known.</param>
+    /// <returns>A boolean which indicates if the name is known.</returns>
+    public static bool TryToNexusDataType(string name, out NexusDataType dataType)
+    {
+        var canonicalName = Enum
+            .GetNames<NexusDataType>()
+            .FirstOrDefault(current => string.Equals(current, name.Trim(), StringComparison.OrdinalIgnoreCase));
+
+        if (canonicalName is not null)
+        {
+            dataType = Enum.Parse<NexusDataType>(canonicalName);
+            return true;
+        }
+
+        dataType = default;
+        return false;
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if the data type is signed. This includes the floating-point data types.
+    /// </summary>
+    /// <param name="dataType">The data type.</param>
+    /// <returns>A boolean which indicates if the data type is signed.</returns>
+    public static bool IsSigned(this NexusDataType dataType)
+    {
+        return ((int)dataType & 0xF00) >= 0x200;
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if the data type is a (signed or unsigned) integer type.
+    /// </summary>
+    /// <param name="dataType">The data type.</param>
+    /// <returns>A boolean which indicates if the data type is an integer type.</returns>
+    public static bool IsInteger(this NexusDataType dataType)
+    {
+        return ((int)dataType & 0xF00) <= 0x200;
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if the data type is a floating-point type.
+    /// </summary>
+    /// <param name="dataType">The data type.</param>
+    /// <returns>A boolean which indicates if the data type is a floating-point type.</returns>
+    public static bool IsFloatingPoint(this NexusDataType dataType)
+    {
+        return ((int)dataType & 0xF00) == 0x300;
+    }
+
+    /// <summary>
+    /// Decodes a single value of the data type into a <see cref="double"/>.
+    /// </summary>
+    /// <param name="dataType">The data type.</param>
+    /// <param name="bytes">The encoded value.</param>
+    /// <param name="byteOrder">The byte order of the encoded value.</param>
+    /// <returns>The decoded value.</returns>
+    /// <exception cref="ArgumentException">Thrown when <paramref name="bytes"/> is too short.</exception>
+    public static double DecodeDouble(this NexusDataType dataType, ReadOnlySpan<byte> bytes, ByteOrder byteOrder = ByteOrder.LittleEndian)
+    {
+        if (bytes.Length < dataType.GetElementSize())
+            throw new ArgumentException($"At least {dataType.GetElementSize()} bytes are required to decode a value of type {dataType}.");
+
+        var isLittleEndian = byteOrder == ByteOrder.LittleEndian;
+
+        return dataType switch
+        {
+            NexusDataType.UINT8 => bytes[0],
+            NexusDataType.INT8 => (sbyte)bytes[0],
+            NexusDataType.UINT16 => isLittleEndian ? BinaryPrimitives.ReadUInt16LittleEndian(bytes) : BinaryPrimitives.ReadUInt16BigEndian(bytes),
+            NexusDataType.INT16 => isLittleEndian ? BinaryPrimitives.ReadInt16LittleEndian(bytes) : BinaryPrimitives.ReadInt16BigEndian(bytes),
+            NexusDataType.UINT32 => isLittleEndian ? BinaryPrimitives.ReadUInt32LittleEndian(bytes) : BinaryPrimitives.ReadUInt32BigEndian(bytes),
+            NexusDataType.INT32 => isLittleEndian ? BinaryPrimitives.ReadInt32LittleEndian(bytes) : BinaryPrimitives.ReadInt32BigEndian(bytes),
+            NexusDataType.UINT64 => isLittleEndian ? BinaryPrimitives.ReadUInt64LittleEndian(bytes) : BinaryPrimitives.ReadUInt64BigEndian(bytes),
+            NexusDataType.INT64 => isLittleEndian ? BinaryPrimitives.ReadInt64LittleEndian(bytes) : BinaryPrimitives.ReadInt64BigEndian(bytes),
+            NexusDataType.FLOAT32 => isLittleEndian ? BinaryPrimitives.ReadSingleLittleEndian(bytes) : BinaryPrimitives.ReadSingleBigEndian(bytes),
+            NexusDataType.FLOAT64 => isLittleEndian ? BinaryPrimitives.ReadDoubleLittleEndian(bytes) : BinaryPrimitives.ReadDoubleBigEndian(bytes),
+            _ => throw new NotSupportedException($"The data type {dataType} is not supported.")
+        };
+    }
+
+    /// <summary>
+    /// Decodes a single value of the representation into a <see cref="double"/> and applies the scale and offset (<c>raw * scale + offset</c>) if present (see <see cref="GetScaling"/>).
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <param name="bytes">The encoded value.</param>
+    /// <param name="byteOrder">The byte order of the encoded value.</param>
+    /// <returns>The decoded value.</returns>
+    /// <exception cref="ArgumentException">Thrown when <paramref name="bytes"/> is too short.</exception>
+    public static double DecodeDouble(this Representation representation, ReadOnlySpan<byte> bytes, ByteOrder byteOrder = ByteOrder.LittleEndian)
+    {
+        var raw = representation.DataType.DecodeDouble(bytes, byteOrder);
+        var scaling = representation.GetScaling();
+
+        return scaling is null
+            ? raw
+            : raw * scaling.Value.Scale + scaling.Value.Offset;
+    }
+
+    /// <summary>
+    /// Encodes a <see cref="double"/> as a single value of the data type. Values outside of the range of integer data types are clamped and <see cref="double.NaN"/> becomes zero. Floating-point data types preserve <see cref="double.NaN"/>.
+    /// </summary>
+    /// <param name="dataType">The data type.</param>
+    /// <param name="value">The value to encode.</param>
+    /// <param name="destination">The buffer to write the encoded value into.</param>
+    /// <param name="byteOrder">The byte order of the encoded value.</param>
+    /// <param name="allowInfinity">A boolean which indicates if infinite values are allowed. If false, an exception is thrown to catch numeric bugs at the write boundary.</param>
+    /// <exception cref="ArgumentException">Thrown when <paramref name="destination"/> is too short or when <paramref name="value"/> is infinite but infinite values are not allowed.</exception>
+    public static void EncodeDouble(this NexusDataType dataType, double value, Span<byte> destination, ByteOrder byteOrder = ByteOrder.LittleEndian, bool allowInfinity = true)
+    {
+        if (destination.Length < dataType.GetElementSize())
+            throw new ArgumentException($"At least {dataType.GetElementSize()} bytes are required to encode a value of type {dataType}.");
+
+        if (!allowInfinity && double.IsInfinity(value))
+            throw new ArgumentException($"The value {value} is infinite but infinite values are not allowed.");
+
+        var isLittleEndian = byteOrder == ByteOrder.LittleEndian;
+
+        switch (dataType)
+        {
+            case NexusDataType.UINT8:
+                destination[0] = byte.CreateSaturating(value);
+                break;
+
+            case NexusDataType.INT8:
+                destination[0] = (byte)sbyte.CreateSaturating(value);
+                break;
+
+            case NexusDataType.UINT16:
+                if (isLittleEndian) BinaryPrimitives.WriteUInt16LittleEndian(destination, ushort.CreateSaturating(value));
+                else BinaryPrimitives.WriteUInt16BigEndian(destination, ushort.CreateSaturating(value));
+                break;
+
+            case NexusDataType.INT16:
+                if (isLittleEndian) BinaryPrimitives.WriteInt16LittleEndian(destination, short.CreateSaturating(value));
+                else BinaryPrimitives.WriteInt16BigEndian(destination, short.CreateSaturating(value));
+                break;
+
+            case NexusDataType.UINT32:
+                if (isLittleEndian) BinaryPrimitives.WriteUInt32LittleEndian(destination, uint.CreateSaturating(value));
+                else BinaryPrimitives.WriteUInt32BigEndian(destination, uint.CreateSaturating(value));
+                break;
+
+            case NexusDataType.INT32:
+                if (isLittleEndian) BinaryPrimitives.WriteInt32LittleEndian(destination, int.CreateSaturating(value));
+                else BinaryPrimitives.WriteInt32BigEndian(destination, int.CreateSaturating(value));
+                break;
+
+            case NexusDataType.UINT64:
+                if (isLittleEndian) BinaryPrimitives.WriteUInt64LittleEndian(destination, ulong.CreateSaturating(value));
+                else BinaryPrimitives.WriteUInt64BigEndian(destination, ulong.CreateSaturating(value));
+                break;
+
+            case NexusDataType.INT64:
+                if (isLittleEndian) BinaryPrimitives.WriteInt64LittleEndian(destination, long.CreateSaturating(value));
+                else BinaryPrimitives.WriteInt64BigEndian(destination, long.CreateSaturating(value));
+                break;
+
+            case NexusDataType.FLOAT32:
+                if (isLittleEndian) BinaryPrimitives.WriteSingleLittleEndian(destination, (float)value);
+                else BinaryPrimitives.WriteSingleBigEndian(destination, (float)value);
+                break;
+
+            case NexusDataType.FLOAT64:
+                if (isLittleEndian) BinaryPrimitives.WriteDoubleLittleEndian(destination, value);
+                else BinaryPrimitives.WriteDoubleBigEndian(destination, value);
+                break;
+
+            default:
+                throw new NotSupportedException($"The data type {dataType} is not supported.");
+        }
     }
 
     #endregion
@@ -135,21 +785,23 @@ public static partial class DataModelExtensions
     }
 
     // keep in sync with Nexus.UI.Utilities ...
-    private const int NS_PER_TICK = 100;
+    internal const int NS_PER_TICK = 100;
     private static readonly long[] _nanoseconds = [(long)1e0, (long)1e3, (long)1e6, (long)1e9, (long)60e9, (long)3600e9, (long)86400e9];
     private static readonly int[] _quotients = [1000, 1000, 1000, 60, 60, 24, 1];
     private static readonly string[] _postFixes = ["ns", "us", "ms", "s", "min", "h", "d"];
     // ... except this line
     private static readonly Regex _unitStringEvaluator = UnitStringEvaluator();
+    private static readonly Regex _lenientUnitStringEvaluator = LenientUnitStringEvaluator();
 
     /// <summary>
-    /// Converts period into a human readable number string with unit.
+    /// Converts period into a human readable number string with unit. The coarsest unit which represents the period without a fractional part is chosen, e.g. a period of 1.5 days becomes <c>36_h</c>. Days are the coarsest unit, e.g. a period of two weeks becomes <c>14_d</c>.
     /// </summary>
     /// <param name="samplePeriod">The period to convert.</param>
     /// <returns>The human readable number string with unit.</returns>
+    /// <exception cref="OverflowException">Thrown when the period is not expressible (see <see cref="IsExpressible"/>).</exception>
     public static string ToUnitString(this TimeSpan samplePeriod)
     {
-        var currentValue = samplePeriod.Ticks * NS_PER_TICK;
+        var currentValue = checked(samplePeriod.Ticks * NS_PER_TICK);
 
         for (int i = 0; i < _postFixes.Length; i++)
         {
@@ -162,34 +814,193 @@ public static partial class DataModelExtensions
                 currentValue = quotient;
         }
 
-        return $"{(int)currentValue}_{_postFixes.Last()}";
+        // the last quotient is 1, so the loop never falls through with a
+        // remainder and the value is already expressed in days
+        return $"{currentValue}_{_postFixes.Last()}";
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if the period can be expressed as a unit string (see <see cref="ToUnitString"/>). Every positive period whose number of nanoseconds fits into a <see cref="long"/> (i.e. up to approximately 292 years) renders to a non-empty unit string which can be parsed back into the same period. Awkward periods fall back to a finer unit, e.g. <c>90100_ns</c>.
+    /// </summary>
+    /// <param name="samplePeriod">The period to check.</param>
+    /// <returns>A boolean which indicates if the period is expressible.</returns>
+    public static bool IsExpressible(this TimeSpan samplePeriod)
+    {
+        return samplePeriod > TimeSpan.Zero && samplePeriod.Ticks <= long.MaxValue / NS_PER_TICK;
+    }
+
+    /// <summary>
+    /// Gets the integer factor between a sample period and a coarser sample period, e.g. 60 for 1 s and 1 min.
+    /// </summary>
+    /// <param name="samplePeriod">The sample period.</param>
+    /// <param name="coarserSamplePeriod">The coarser sample period.</param>
+    /// <returns>The factor or <see langword="null"/> if the coarser sample period is not an exact multiple of the sample period.</returns>
+    public static long? GetFactorTo(this TimeSpan samplePeriod, TimeSpan coarserSamplePeriod)
+    {
+        if (samplePeriod <= TimeSpan.Zero || coarserSamplePeriod < samplePeriod)
+            return default;
+
+        if (coarserSamplePeriod.Ticks % samplePeriod.Ticks != 0)
+            return default;
+
+        return coarserSamplePeriod.Ticks / samplePeriod.Ticks;
+    }
+
+    /// <summary>
+    /// Converts a sample period into an integer number of nanoseconds.
+    /// </summary>
+    /// <param name="samplePeriod">The period to convert.</param>
+    /// <returns>The number of nanoseconds.</returns>
+    public static ulong ToNanoseconds(this TimeSpan samplePeriod)
+    {
+        if (samplePeriod <= TimeSpan.Zero)
+            throw new ArgumentException("The sample period must be greater than zero.");
+
+        if ((ulong)samplePeriod.Ticks > ulong.MaxValue / NS_PER_TICK)
+            throw new ArgumentException("The sample period exceeds the maximum number of nanoseconds.");
+
+        return (ulong)samplePeriod.Ticks * NS_PER_TICK;
+    }
+
+    /// <summary>
+    /// Converts an integer number of nanoseconds into a sample period.
+    /// </summary>
+    /// <param name="nanoseconds">The number of nanoseconds.</param>
+    /// <returns>The sample period.</returns>
+    public static TimeSpan FromNanoseconds(ulong nanoseconds)
+    {
+        if (nanoseconds == 0)
+            throw new ArgumentException("The sample period must be greater than zero.");
+
+        if (nanoseconds % NS_PER_TICK != 0)
+            throw new ArgumentException("The sample period must be a multiple of 100 ns.");
+
+        return new TimeSpan((long)(nanoseconds / NS_PER_TICK));
+    }
+
+    private static readonly TimeSpan[] _canonicalPeriods =
+    [
+        TimeSpan.FromTicks(1),
+        TimeSpan.FromMicroseconds(1),
+        TimeSpan.FromMicroseconds(10),
+        TimeSpan.FromMicroseconds(100),
+        TimeSpan.FromMilliseconds(1),
+        TimeSpan.FromMilliseconds(10),
+        TimeSpan.FromMilliseconds(100),
+        TimeSpan.FromSeconds(1),
+        TimeSpan.FromSeconds(10),
+        TimeSpan.FromMinutes(1),
+        TimeSpan.FromMinutes(10),
+        TimeSpan.FromHours(1),
+        TimeSpan.FromDays(1)
+    ];
+
+    /// <summary>
+    /// Snaps a sample period to the nearest canonical period for presentation purposes (e.g. <c>1333_ms</c> becomes <c>1_s</c>). The canonical periods are <c>100_ns</c>, <c>1_us</c>, <c>10_us</c>, <c>100_us</c>, <c>1_ms</c>, <c>10_ms</c>, <c>100_ms</c>, <c>1_s</c>, <c>10_s</c>, <c>1_min</c>, <c>10_min</c>, <c>1_h</c> and <c>1_d</c>. On a tie, the smaller period is chosen.
+    /// </summary>
+    /// <param name="samplePeriod">The period to snap.</param>
+    /// <returns>The nearest canonical period.</returns>
+    /// <exception cref="ArgumentException">Thrown when the sample period is not greater than zero.</exception>
+    public static TimeSpan ToNearestCanonical(this TimeSpan samplePeriod)
+    {
+        if (samplePeriod <= TimeSpan.Zero)
+            throw new ArgumentException("The sample period must be greater than zero.");
+
+        return _canonicalPeriods
+            .MinBy(canonicalPeriod => (canonicalPeriod - samplePeriod).Duration());
     }
 
     // this method is placed here because it requires access to _postFixes and _nanoseconds
-    internal static TimeSpan ToSamplePeriod(string unitString)
+    /// <summary>
+    /// Converts a unit string like <c>10_ms</c> or <c>15_min</c> into a sample period. This is the inverse of <see cref="ToUnitString"/>. The supported units are <c>ns</c>, <c>us</c>, <c>ms</c>, <c>s</c>, <c>min</c>, <c>h</c> and <c>d</c>.
+    /// </summary>
+    /// <param name="unitString">The unit string to convert.</param>
+    /// <returns>The sample period.</returns>
+    /// <exception cref="Exception">Thrown when the unit string has no underscore, a non-numeric or zero value or an unknown unit or when the sample period is not a multiple of 100 ns.</exception>
+    public static TimeSpan ToSamplePeriod(string unitString)
     {
+        var errorMessage = ParseSamplePeriod(unitString, out var samplePeriod);
+
+        if (errorMessage is not null)
+            throw new Exception(errorMessage);
+
+        return samplePeriod;
+    }
+
+    /// <summary>
+    /// Parses user input like <c>10 min</c>, <c>10min</c>, <c>10_min</c> or <c>1 S</c> into a sample period. Inputs which are the beginning of a valid sample period (e.g. <c>10</c> or <c>10 mi</c>) are reported as <see cref="SamplePeriodParseStatus.Incomplete"/> so that a UI can reparse on each keystroke without resetting the field.
+    /// </summary>
+    /// <param name="input">The user input.</param>
+    /// <param name="samplePeriod">The parsed sample period if parsing succeeded.</param>
+    /// <returns>The parse status.</returns>
+    public static SamplePeriodParseStatus ParseSamplePeriodLenient(string input, out TimeSpan samplePeriod)
+    {
+        samplePeriod = default;
+
+        if (string.IsNullOrWhiteSpace(input))
+            return SamplePeriodParseStatus.Incomplete;
+
+        var match = _lenientUnitStringEvaluator.Match(input);
+
+        if (!match.Success)
+            return SamplePeriodParseStatus.Invalid;
+
+        var value = match.Groups[1].Value;
+        var unit = match.Groups[2].Value.ToLowerInvariant();
+
+        if (_postFixes.Contains(unit))
+        {
+            return ParseSamplePeriod($"{value}_{unit}", out samplePeriod) is null
+                ? SamplePeriodParseStatus.Success
+                : SamplePeriodParseStatus.Invalid;
+        }
+
+        return _postFixes.Any(postFix => postFix.StartsWith(unit, StringComparison.Ordinal))
+            ? SamplePeriodParseStatus.Incomplete
+            : SamplePeriodParseStatus.Invalid;
+    }
+
+    internal static bool TryToSamplePeriod(string unitString, out TimeSpan samplePeriod)
+    {
+        return ParseSamplePeriod(unitString, out samplePeriod) is null;
+    }
+
+    private static string? ParseSamplePeriod(string unitString, out TimeSpan samplePeriod)
+    {
+        samplePeriod = default;
+
         var match = _unitStringEvaluator.Match(unitString);
 
         if (!match.Success)
-            throw new Exception("The provided unit string is invalid.");
+            return "The provided unit string is invalid.";
 
         var unitIndex = Array.IndexOf(_postFixes, match.Groups[2].Value);
 
         if (unitIndex == -1)
-            throw new Exception("The provided unit is invalid.");
+            return "The provided unit is invalid.";
+
+        if (!long.TryParse(match.Groups[1].Value, out var value) || value > long.MaxValue / _nanoseconds[unitIndex])
+            return "The provided value is too large.";
 
-        var totalNanoSeconds = long.Parse(match.Groups[1].Value) * _nanoseconds[unitIndex];
+        if (value == 0)
+            return "The sample period must be greater than zero.";
+
+        var totalNanoSeconds = value * _nanoseconds[unitIndex];
 
         if (totalNanoSeconds % NS_PER_TICK != 0)
-            throw new Exception("The sample period must be a multiple of 100 ns.");
+            return "The sample period must be a multiple of 100 ns.";
 
         var ticks = totalNanoSeconds / NS_PER_TICK;
+        samplePeriod = new TimeSpan(ticks);
 
-        return new TimeSpan(ticks);
+        return default;
     }
 
     [GeneratedRegex(@"^([0-9]+)_([a-z]+)$", RegexOptions.Compiled)]
     private static partial Regex UnitStringEvaluator();
 
+    [GeneratedRegex(@"^\s*([0-9]+)\s*_?\s*([a-zA-Z]*)\s*$", RegexOptions.Compiled)]
+    private static partial Regex LenientUnitStringEvaluator();
+
     #endregion
 }
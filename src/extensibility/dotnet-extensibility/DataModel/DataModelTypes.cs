@@ -1,20 +1,69 @@
 ﻿// MIT License
 // Copyright (c) [2024] [nexus-main]
 
+using Nexus.Extensibility;
+using System.Diagnostics.CodeAnalysis;
+
 namespace Nexus.DataModel;
 
-internal enum RepresentationKind
+/// <summary>
+/// Specifies the kind of a representation, i.e. whether it contains original data or how it has been derived from it.
+/// </summary>
+public enum RepresentationKind
 {
+    /// <summary>
+    /// The original data as provided by the data source.
+    /// </summary>
     Original = 0,
+
+    /// <summary>
+    /// The data resampled to a finer sample period.
+    /// </summary>
     Resampled = 10,
+
+    /// <summary>
+    /// The arithmetic mean.
+    /// </summary>
     Mean = 20,
+
+    /// <summary>
+    /// The mean of angles given in degrees.
+    /// </summary>
     MeanPolarDeg = 30,
+
+    /// <summary>
+    /// The minimum.
+    /// </summary>
     Min = 40,
+
+    /// <summary>
+    /// The maximum.
+    /// </summary>
     Max = 50,
+
+    /// <summary>
+    /// The standard deviation.
+    /// </summary>
     Std = 60,
+
+    /// <summary>
+    /// The root mean square.
+    /// </summary>
     Rms = 70,
+
+    /// <summary>
+    /// The bitwise AND of all values.
+    /// </summary>
     MinBitwise = 80,
+
+    /// <summary>
+    /// The bitwise OR of all values.
+    /// </summary>
     MaxBitwise = 90,
+
+    /// <summary>
+    /// The sum.
+    /// </summary>
     Sum = 100
 }
 
@@ -74,6 +123,64 @@ public enum NexusDataType : ushort
     FLOAT64 = 0x340
 }
 
+/// <summary>
+/// Specifies the byte order of encoded values.
+/// </summary>
+public enum ByteOrder
+{
+    /// <summary>
+    /// The least significant byte comes first.
+    /// </summary>
+    LittleEndian,
+
+    /// <summary>
+    /// The most significant byte comes first.
+    /// </summary>
+    BigEndian
+}
+
+/// <summary>
+/// Specifies how values between two samples should be interpreted.
+/// </summary>
+public enum InterpolationMode
+{
+    /// <summary>
+    /// The interpolation is not specified.
+    /// </summary>
+    None,
+
+    /// <summary>
+    /// Values are linearly interpolated between samples.
+    /// </summary>
+    Linear,
+
+    /// <summary>
+    /// Values are held until the next sample.
+    /// </summary>
+    Step
+}
+
+/// <summary>
+/// Specifies the outcome of lenient sample period parsing.
+/// </summary>
+public enum SamplePeriodParseStatus
+{
+    /// <summary>
+    /// The input is a valid sample period.
+    /// </summary>
+    Success,
+
+    /// <summary>
+    /// The input is a valid prefix of a sample period, e.g. <c>10 mi</c>, but not yet complete.
+    /// </summary>
+    Incomplete,
+
+    /// <summary>
+    /// The input is not a valid sample period.
+    /// </summary>
+    Invalid
+}
+
 /// <summary>
 /// A catalog item consists of a catalog, a resource and a representation.
 /// </summary>
@@ -81,10 +188,56 @@ public enum NexusDataType : ushort
 /// <param name="Resource">The resource.</param>
 /// <param name="Representation">The representation.</param>
 /// <param name="Parameters">The optional dictionary of representation parameters and its arguments.</param>
+/// <remarks>
+/// Two catalog items are equal when their catalog identifiers, resource identifiers, representation identifiers and parameters match. All other members of the catalog, the resource and the representation (e.g. properties or child resources) are ignored so that catalog items can be used as cache keys.
+/// </remarks>
 public record CatalogItem(ResourceCatalog Catalog, Resource Resource, Representation Representation, IReadOnlyDictionary<string, string>? Parameters)
 {
+    /// <inheritdoc />
+    public virtual bool Equals(CatalogItem? other)
+    {
+        if (other is null)
+            return false;
+
+        if (ReferenceEquals(this, other))
+            return true;
+
+        return
+            Catalog.Id == other.Catalog.Id &&
+            Resource.Id == other.Resource.Id &&
+            Representation.Id == other.Representation.Id &&
+            ParametersEqual(Parameters, other.Parameters);
+    }
+
+    /// <inheritdoc />
+    public override int GetHashCode()
+    {
+        var hashCode = new HashCode();
+
+        hashCode.Add(Catalog.Id);
+        hashCode.Add(Resource.Id);
+        hashCode.Add(Representation.Id);
+
+        foreach (var (key, value) in GetSortedParameters())
+        {
+            hashCode.Add(key);
+            hashCode.Add(value);
+        }
+
+        return hashCode.ToHashCode();
+    }
+
     /// <summary>
-    /// Construct a fully qualified path.
+    /// Gets the parameters sorted by key to allow for a deterministic iteration order.
+    /// </summary>
+    /// <returns>The sorted list of parameters.</returns>
+    public IReadOnlyList<(string Key, string Value)> GetSortedParameters()
+    {
+        return DataModelUtilities.GetSortedParameters(Parameters);
+    }
+
+    /// <summary>
+    /// Construct a fully qualified path. The parameters are sorted by key so that equal catalog items produce identical paths.
     /// </summary>
     /// <returns>The fully qualified path.</returns>
     public string ToPath()
@@ -92,6 +245,107 @@ public record CatalogItem(ResourceCatalog Catalog, Resource Resource, Representa
         var parametersString = DataModelUtilities.GetRepresentationParameterString(Parameters);
         return $"{Catalog.Id}/{Resource.Id}/{Representation.Id}{parametersString}";
     }
+
+    /// <summary>
+    /// Construct a path to be used in HTTP API routes. The catalog identifier is percent-encoded as a single path segment (e.g. <c>/A/B</c> becomes <c>%2FA%2FB</c>) and so is the optional parameter string (e.g. <c>(a=1)</c> becomes <c>%28a%3D1%29</c>). The resource and representation identifiers consist of URL-safe characters only and are therefore appended unchanged, separated by a plain <c>/</c>.
+    /// </summary>
+    /// <returns>The API path.</returns>
+    public string ToApiPath()
+    {
+        var parametersString = DataModelUtilities.GetRepresentationParameterString(Parameters);
+
+        var encodedParametersString = parametersString is null
+            ? default
+            : Uri.EscapeDataString(parametersString);
+
+        return $"{Uri.EscapeDataString(Catalog.Id)}/{Resource.Id}/{Representation.Id}{encodedParametersString}";
+    }
+
+    /// <summary>
+    /// Parses a path produced by <see cref="ToApiPath"/> and resolves it against the provided catalog.
+    /// </summary>
+    /// <param name="apiPath">The percent-encoded API path.</param>
+    /// <param name="catalog">The catalog to resolve the path against.</param>
+    /// <returns>The catalog item.</returns>
+    /// <exception cref="ArgumentException">Thrown when the API path is malformed.</exception>
+    /// <exception cref="Exception">Thrown when the API path could not be found in the catalog.</exception>
+    public static CatalogItem ParseApiPath(string apiPath, ResourceCatalog catalog)
+    {
+        if (DataModelUtilities.InvalidPercentEncodingExpression.IsMatch(apiPath))
+            throw new ArgumentException($"The API path {apiPath} contains an invalid percent-encoding.");
+
+        var separatorIndex = apiPath.IndexOf('/');
+
+        if (separatorIndex == -1)
+            throw new ArgumentException($"The API path {apiPath} is not valid.");
+
+        var catalogId = Uri.UnescapeDataString(apiPath[..separatorIndex]);
+
+        if (!ResourceCatalog.ValidIdExpression.IsMatch(catalogId))
+            throw new ArgumentException($"The resource catalog identifier {catalogId} of the API path is not valid.");
+
+        var remainder = Uri.UnescapeDataString(apiPath[(separatorIndex + 1)..]);
+
+        return catalog.Find($"{catalogId}/{remainder}");
+    }
+
+    private static bool ParametersEqual(IReadOnlyDictionary<string, string>? parameters1, IReadOnlyDictionary<string, string>? parameters2)
+    {
+        if (parameters1 is null || parameters2 is null)
+            return parameters1 is null && parameters2 is null;
+
+        return parameters1.Count == parameters2.Count && parameters1.All(parameter =>
+            parameters2.TryGetValue(parameter.Key, out var value) && value == parameter.Value);
+    }
+}
+
+/// <summary>
+/// Describes the physical sensor behind a resource.
+/// </summary>
+/// <param name="Serial">The optional serial number.</param>
+/// <param name="CalibratedAt">The optional date/time of the last calibration.</param>
+/// <param name="Location">The optional location.</param>
+public record SensorMetadata(string? Serial = default, DateTime? CalibratedAt = default, string? Location = default);
+
+/// <summary>
+/// A virtual resource is derived from other resources of the same catalog by evaluating an arithmetic expression.
+/// </summary>
+/// <param name="Resource">The resource.</param>
+/// <param name="Expression">The expression which refers to other resource identifiers, e.g. <c>voltage * current</c>.</param>
+/// <exception cref="ArgumentException">Thrown when the expression is invalid, does not refer to any resource, refers to an invalid resource identifier or to the virtual resource itself.</exception>
+public record VirtualResource(Resource Resource, string Expression)
+{
+    /// <summary>
+    /// Gets the expression which refers to other resource identifiers.
+    /// </summary>
+    public string Expression { get; init; } = ValidateExpression(Resource, Expression);
+
+    /// <summary>
+    /// Gets the identifiers of the resources the expression refers to.
+    /// </summary>
+    public IReadOnlyList<string> InputIds => ExpressionEvaluator.GetIdentifiers(Expression);
+
+    private static string ValidateExpression(Resource resource, string expression)
+    {
+        if (string.IsNullOrWhiteSpace(expression))
+            throw new ArgumentException("The expression of a virtual resource must not be empty.");
+
+        var identifiers = ExpressionEvaluator.GetIdentifiers(expression);
+
+        if (identifiers.Count == 0)
+            throw new ArgumentException($"The expression of the virtual resource {resource.Id} does not refer to any resource.");
+
+        foreach (var identifier in identifiers)
+        {
+            if (!Resource.ValidIdExpression.IsMatch(identifier))
+                throw new ArgumentException($"The expression of the virtual resource {resource.Id} refers to the invalid resource identifier {identifier}.");
+
+            if (identifier == resource.Id)
+                throw new ArgumentException($"The expression of the virtual resource {resource.Id} refers to the virtual resource itself.");
+        }
+
+        return expression;
+    }
 }
 
 /// <summary>
@@ -100,7 +354,8 @@ public record CatalogItem(ResourceCatalog Catalog, Resource Resource, Representa
 /// <param name="Path">The absolute or relative path of the catalog.</param>
 /// <param name="Title">A nullable title.</param>
 /// <param name="IsTransient">A boolean which indicates if the catalog and its children should be reloaded on each request.</param>
-public record CatalogRegistration(string Path, string? Title, bool IsTransient = false)
+/// <param name="LinkTarget">An optional path of another catalog which this catalog is a softlink to.</param>
+public record CatalogRegistration(string Path, string? Title, bool IsTransient = false, string? LinkTarget = default)
 {
     /// <summary>
     /// Gets the absolute or relative path of the catalog.
@@ -109,6 +364,17 @@ public record CatalogRegistration(string Path, string? Title, bool IsTransient =
         ? Path
         : throw new ArgumentException($"The catalog path {Path} is not valid.");
 
+    /// <summary>
+    /// Gets the optional path of another catalog which this catalog is a softlink to.
+    /// </summary>
+    public string? LinkTarget { get; init; } = LinkTarget is null
+        ? LinkTarget
+        : !IsValidPath(LinkTarget)
+            ? throw new ArgumentException($"The link target {LinkTarget} is not valid.")
+            : LinkTarget == Path
+                ? throw new ArgumentException($"The catalog {Path} must not be a softlink to itself.")
+                : LinkTarget;
+
     /// <summary>
     /// Gets the nullable title.
     /// </summary>
@@ -121,7 +387,7 @@ public record CatalogRegistration(string Path, string? Title, bool IsTransient =
 
     private static bool IsValidPath(string path)
     {
-        if (path == "/")
+        if (path == ResourceCatalog.RootPath)
             return true;
 
         if (!path.StartsWith("/"))
@@ -134,11 +400,50 @@ public record CatalogRegistration(string Path, string? Title, bool IsTransient =
 }
 
 // keep in sync with Nexus.UI.Utilities
-internal record ResourcePathParseResult(
+/// <summary>
+/// The result of parsing a resource path.
+/// </summary>
+/// <param name="CatalogId">The catalog identifier.</param>
+/// <param name="ResourceId">The resource identifier.</param>
+/// <param name="SamplePeriod">The sample period.</param>
+/// <param name="Kind">The representation kind.</param>
+/// <param name="Parameters">The optional raw parameter string, e.g. <c>foo=bar</c>.</param>
+/// <param name="BasePeriod">The optional base period.</param>
+public record ResourcePathParseResult(
     string CatalogId,
     string ResourceId,
     TimeSpan SamplePeriod,
     RepresentationKind Kind,
     string? Parameters,
     TimeSpan? BasePeriod
-);
+)
+{
+    /// <summary>
+    /// Parses a resource path like <c>/A/B/temp/10_ms_mean(foo=bar)#base=1_ms</c> into its components.
+    /// </summary>
+    /// <param name="resourcePath">The resource path.</param>
+    /// <returns>The parse result.</returns>
+    /// <exception cref="FormatException">Thrown when the resource path is malformed.</exception>
+    public static ResourcePathParseResult Parse(string resourcePath)
+    {
+        var errorMessage = DataModelUtilities.ParseResourcePathCore(resourcePath, out var parseResult);
+
+        if (errorMessage is not null)
+            throw new FormatException($"The resource path {resourcePath} is invalid: {errorMessage}");
+
+        return parseResult!;
+    }
+
+    /// <summary>
+    /// Tries to parse a resource path like <c>/A/B/temp/10_ms_mean(foo=bar)#base=1_ms</c> into its components.
+    /// </summary>
+    /// <param name="resourcePath">The resource path.</param>
+    /// <param name="parseResult">The parse result.</param>
+    /// <returns>True when the resource path is well-formed, otherwise false.</returns>
+    public static bool TryParse(
+        string resourcePath,
+        [NotNullWhen(returnValue: true)] out ResourcePathParseResult? parseResult)
+    {
+        return DataModelUtilities.TryParseResourcePath(resourcePath, out parseResult);
+    }
+}
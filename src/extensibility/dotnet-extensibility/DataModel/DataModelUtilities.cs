@@ -23,6 +23,8 @@ internal static partial class DataModelUtilities
     // keep in sync with Nexus.UI.Core.Utilities
     private static readonly Regex _resourcePathEvaluator = ResourcePathEvaluator();
 
+    public static Regex InvalidPercentEncodingExpression { get; } = InvalidPercentEncodingEvaluator();
+
     private static string ToPascalCase(string input)
     {
         var camelCase = Regex.Replace(input, "_.", match => match.Value[1..].ToUpper());
@@ -35,6 +37,13 @@ internal static partial class DataModelUtilities
     public static bool TryParseResourcePath(
         string resourcePath,
         [NotNullWhen(returnValue: true)] out ResourcePathParseResult? parseResult)
+    {
+        return ParseResourcePathCore(resourcePath, out parseResult) is null;
+    }
+
+    public static string? ParseResourcePathCore(
+        string resourcePath,
+        out ResourcePathParseResult? parseResult)
     {
         parseResult = default;
 
@@ -42,7 +51,11 @@ internal static partial class DataModelUtilities
         var match = _resourcePathEvaluator.Match(resourcePath);
 
         if (!match.Success)
-            return false;
+            return "The path does not consist of a catalog identifier, a resource identifier and a representation.";
+
+        // sample period
+        if (!DataModelExtensions.TryToSamplePeriod(match.Groups["sample_period"].Value, out var samplePeriod))
+            return $"The sample period {match.Groups["sample_period"].Value} is invalid.";
 
         // kind
         var kind = RepresentationKind.Original;
@@ -52,7 +65,7 @@ internal static partial class DataModelUtilities
             var rawValue = match.Groups["kind"].Value;
 
             if (!Enum.TryParse(ToPascalCase(rawValue), out kind))
-                return default;
+                return $"The representation kind {rawValue} is unknown.";
         }
 
         // basePeriod
@@ -60,21 +73,36 @@ internal static partial class DataModelUtilities
 
         if (match.Groups["fragment"].Success)
         {
-            var unitString = match.Groups["fragment"].Value.Split('=', count: 2)[1];
-            basePeriod = DataModelExtensions.ToSamplePeriod(unitString);
+            var fragment = match.Groups["fragment"].Value.Split('=', count: 2);
+
+            if (fragment.Length != 2 || !DataModelExtensions.TryToSamplePeriod(fragment[1], out var parsedBasePeriod))
+                return $"The fragment {match.Groups["fragment"].Value} is invalid.";
+
+            basePeriod = parsedBasePeriod;
         }
 
         // result
         parseResult = new ResourcePathParseResult(
             CatalogId: match.Groups["catalog"].Value,
             ResourceId: match.Groups["resource"].Value,
-            SamplePeriod: DataModelExtensions.ToSamplePeriod(match.Groups["sample_period"].Value),
+            SamplePeriod: samplePeriod,
             Kind: kind,
             Parameters: match.Groups["parameters"].Success ? match.Groups["parameters"].Value : default,
             BasePeriod: basePeriod
         );
 
-        return true;
+        return default;
+    }
+
+    public static IReadOnlyList<(string Key, string Value)> GetSortedParameters(IReadOnlyDictionary<string, string>? parameters)
+    {
+        if (parameters is null)
+            return [];
+
+        return parameters
+            .OrderBy(parameter => parameter.Key, StringComparer.Ordinal)
+            .Select(parameter => (parameter.Key, parameter.Value))
+            .ToList();
     }
 
     public static string? GetRepresentationParameterString(IReadOnlyDictionary<string, string>? parameters)
@@ -82,7 +110,8 @@ internal static partial class DataModelUtilities
         if (parameters is null)
             return default;
 
-        var serializedParameters = parameters
+        /* sort parameters so that equal catalog items produce identical paths and cache keys */
+        var serializedParameters = GetSortedParameters(parameters)
             .Select(parameter => $"{parameter.Key}={parameter.Value}");
 
         var parametersString = $"({string.Join(',', serializedParameters)})";
@@ -90,6 +119,88 @@ internal static partial class DataModelUtilities
         return parametersString;
     }
 
+    public static void ValidateWellKnownProperty(string key, JsonElement value)
+    {
+        switch (key)
+        {
+            case DataModelExtensions.ReadmeKey:
+            case DataModelExtensions.LicenseKey:
+            case DataModelExtensions.DescriptionKey:
+            case DataModelExtensions.WarningKey:
+            case DataModelExtensions.UnitKey:
+            case DataModelExtensions.UnitNameKey:
+
+                if (value.ValueKind != JsonValueKind.String)
+                    throw new ArgumentException($"The value of the property {key} must be a string.");
+
+                break;
+
+            case DataModelExtensions.GroupsKey:
+
+                if (value.ValueKind != JsonValueKind.Array)
+                    throw new ArgumentException($"The value of the property {key} must be an array of strings.");
+
+                foreach (var group in value.EnumerateArray())
+                {
+                    if (group.ValueKind != JsonValueKind.String)
+                        throw new ArgumentException($"The value of the property {key} must be an array of strings.");
+
+                    var groupString = group.GetString()!;
+
+                    if (groupString.Contains('\n') || groupString.Contains('\r') || groupString.Contains(','))
+                        throw new ArgumentException($"The group {groupString} must not contain line breaks or commas.");
+                }
+
+                break;
+        }
+    }
+
+    public static void CollectStrictPropertyProblems(
+        Dictionary<string, JsonElement> properties,
+        string key,
+        JsonElement value,
+        ref List<string>? problems)
+    {
+        if (properties.ContainsKey(key))
+        {
+            problems ??= [];
+            problems.Add($"The property {key} is set more than once.");
+        }
+
+        var isEmpty = value.ValueKind switch
+        {
+            JsonValueKind.Null => true,
+            JsonValueKind.String => string.IsNullOrWhiteSpace(value.GetString()),
+            JsonValueKind.Array => value.GetArrayLength() == 0,
+            JsonValueKind.Object => !value.EnumerateObject().Any(),
+            _ => false
+        };
+
+        if (isEmpty)
+        {
+            problems ??= [];
+            problems.Add($"The value of the property {key} is empty.");
+        }
+    }
+
+    public static void ThrowOnStrictProblems(List<string>? problems)
+    {
+        if (problems is not null && problems.Count > 0)
+            throw new AggregateException(
+                "The builder has detected one or more problems in strict mode.",
+                problems.Select(problem => new ArgumentException(problem)));
+    }
+
+    public static string[] NormalizeGroups(IEnumerable<string?> groups)
+    {
+        return groups
+            .Where(group => group is not null)
+            .Select(group => group!.Trim())
+            .Where(group => group.Length > 0)
+            .Distinct()
+            .ToArray();
+    }
+
     public static List<Resource>? MergeResources(IReadOnlyList<Resource>? resources1, IReadOnlyList<Resource>? resources2)
     {
         if (resources1 is null && resources2 is null)
@@ -127,7 +238,7 @@ internal static partial class DataModelUtilities
         return mergedResources;
     }
 
-    public static List<Representation>? MergeRepresentations(IReadOnlyList<Representation>? representations1, IReadOnlyList<Representation>? representations2)
+    public static List<Representation>? MergeRepresentations(string resourceId, IReadOnlyList<Representation>? representations1, IReadOnlyList<Representation>? representations2)
     {
         if (representations1 is null && representations2 is null)
             return null;
@@ -152,8 +263,10 @@ internal static partial class DataModelUtilities
 
             if (index >= 0)
             {
-                if (!newRepresentation.Equals(mergedRepresentations[index]))
-                    throw new Exception("The representations to be merged are not equal.");
+                var existingRepresentation = mergedRepresentations[index];
+
+                if (!AreIdentical(newRepresentation, existingRepresentation) || !HaveIdenticalProperties(newRepresentation, existingRepresentation))
+                    throw new Exception($"The representation {newRepresentation.Id} of resource {resourceId} is provided by both resources to be merged but their definitions differ.");
 
             }
 
@@ -166,6 +279,27 @@ internal static partial class DataModelUtilities
         return mergedRepresentations;
     }
 
+    public static bool AreIdentical(Representation representation1, Representation representation2)
+    {
+        return
+            representation1.DataType == representation2.DataType &&
+            representation1.SamplePeriod == representation2.SamplePeriod &&
+            representation1.Kind == representation2.Kind &&
+            SerializeSorted(representation1.Parameters) == SerializeSorted(representation2.Parameters);
+    }
+
+    public static bool HaveIdenticalProperties(Representation representation1, Representation representation2)
+    {
+        return SerializeSorted(representation1.Properties) == SerializeSorted(representation2.Properties);
+    }
+
+    private static string? SerializeSorted(IReadOnlyDictionary<string, JsonElement>? dictionary)
+    {
+        return dictionary is null
+            ? default
+            : JsonSerializer.Serialize(dictionary.OrderBy(entry => entry.Key, StringComparer.Ordinal));
+    }
+
     public static IReadOnlyDictionary<string, JsonElement>? MergeProperties(IReadOnlyDictionary<string, JsonElement>? properties1, IReadOnlyDictionary<string, JsonElement>? properties2)
     {
         if (properties1 is null)
@@ -289,6 +423,9 @@ internal static partial class DataModelUtilities
         };
     }
 
+    [GeneratedRegex(@"%(?![0-9a-fA-F]{2})", RegexOptions.Compiled)]
+    private static partial Regex InvalidPercentEncodingEvaluator();
+
     [GeneratedRegex(@"^(?'catalog'.*)\/(?'resource'.*)\/(?'sample_period'[0-9]+_[a-zA-Z]+)(?:_(?'kind'[^\(#\s]+))?(?:\((?'parameters'.*)\))?(?:#(?'fragment'.*))?$", RegexOptions.Compiled)]
     private static partial Regex ResourcePathEvaluator();
 }
\ No newline at end of file
@@ -0,0 +1,36 @@
+﻿// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+namespace Nexus.DataModel;
+
+/// <summary>
+/// Tracks the sample period of a text field which is reparsed on each keystroke. The last valid sample period is preserved while the text is incomplete or invalid, so that e.g. typing <c>10 min</c> after a prior value of <c>1 s</c> does not revert to <c>1 s</c> mid-entry.
+/// </summary>
+/// <param name="initialValue">The initial sample period.</param>
+public class PeriodInputState(TimeSpan initialValue)
+{
+    /// <summary>
+    /// Gets the last valid sample period.
+    /// </summary>
+    public TimeSpan Value { get; private set; } = initialValue;
+
+    /// <summary>
+    /// Gets the status of the most recent update.
+    /// </summary>
+    public SamplePeriodParseStatus Status { get; private set; } = SamplePeriodParseStatus.Success;
+
+    /// <summary>
+    /// Parses the current text (see <see cref="DataModelExtensions.ParseSamplePeriodLenient"/>) and updates <see cref="Value"/> if it is a valid sample period.
+    /// </summary>
+    /// <param name="text">The current text.</param>
+    /// <returns>The parse status of the current text.</returns>
+    public SamplePeriodParseStatus Update(string text)
+    {
+        Status = DataModelExtensions.ParseSamplePeriodLenient(text, out var samplePeriod);
+
+        if (Status == SamplePeriodParseStatus.Success)
+            Value = samplePeriod;
+
+        return Status;
+    }
+}
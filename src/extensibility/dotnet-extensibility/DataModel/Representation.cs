@@ -25,21 +25,34 @@ public partial record Representation
     /// <param name="dataType">The <see cref="NexusDataType"/>.</param>
     /// <param name="samplePeriod">The sample period.</param>
     /// <param name="parameters">An optional list of representation parameters.</param>
+    /// <param name="properties">The optional properties.</param>
     /// <exception cref="ArgumentException">Thrown when the resource identifier, the sample period or the detail values are not valid.</exception>
     public Representation(
         NexusDataType dataType,
         TimeSpan samplePeriod,
-        IReadOnlyDictionary<string, JsonElement>? parameters = default)
-        : this(dataType, samplePeriod, parameters, RepresentationKind.Original)
+        IReadOnlyDictionary<string, JsonElement>? parameters = default,
+        IReadOnlyDictionary<string, JsonElement>? properties = default)
+        : this(dataType, samplePeriod, parameters, RepresentationKind.Original, properties)
     {
         //
     }
 
-    internal Representation(
+    /// <summary>
+    /// Initializes a new instance of the <see cref="Representation"/> with the specified kind.
+    /// </summary>
+    /// <param name="dataType">The <see cref="NexusDataType"/>.</param>
+    /// <param name="samplePeriod">The sample period.</param>
+    /// <param name="parameters">An optional list of representation parameters.</param>
+    /// <param name="kind">The representation kind.</param>
+    /// <param name="properties">The optional properties.</param>
+    /// <exception cref="ArgumentException">Thrown when the data type, the sample period or the kind are not valid.</exception>
+    [JsonConstructor]
+    public Representation(
         NexusDataType dataType,
         TimeSpan samplePeriod,
         IReadOnlyDictionary<string, JsonElement>? parameters,
-        RepresentationKind kind)
+        RepresentationKind kind,
+        IReadOnlyDictionary<string, JsonElement>? properties = default)
     {
         // data type
         if (!_nexusDataTypeValues.Contains(dataType))
@@ -48,7 +61,7 @@ public partial record Representation
         DataType = dataType;
 
         // sample period
-        if (samplePeriod.Equals(default))
+        if (!samplePeriod.IsExpressible())
             throw new ArgumentException($"The sample period {samplePeriod} is not valid.");
 
         SamplePeriod = samplePeriod;
@@ -62,18 +75,15 @@ public partial record Representation
 
         Kind = kind;
 
-        // id
-        Id = SamplePeriod.ToUnitString();
+        // properties
+        Properties = properties;
 
-        if (kind != RepresentationKind.Original)
-        {
-            var snakeCaseKind = _snakeCaseEvaluator.Replace(kind.ToString(), "_$1").Trim().ToLower();
-            Id = $"{Id}_{snakeCaseKind}";
-        }
+        // id
+        Id = GetId(samplePeriod, kind);
     }
 
     /// <summary>
-    /// The identifer of the representation. It is constructed using the sample period.
+    /// The identifer of the representation. It is constructed using the sample period and the kind. The parameters are not part of the identifier so that parameterized variants can be grouped by it. Use <see cref="CatalogItem.ToPath"/> to get a path which includes the parameter values.
     /// </summary>
     [JsonIgnore]
     public string Id { get; }
@@ -107,18 +117,113 @@ public partial record Representation
         }
     }
 
+    /// <summary>
+    /// The optional properties.
+    /// </summary>
+    public IReadOnlyDictionary<string, JsonElement>? Properties { get; init; }
+
     /// <summary>
     /// The representation kind.
     /// </summary>
-    [JsonIgnore]
-#warning Remove this when https://github.com/RicoSuter/NSwag/issues/4681 is solved
-    internal RepresentationKind Kind { get; }
+    public RepresentationKind Kind { get; }
 
     /// <summary>
     /// The number of bits per element.
     /// </summary>
     [JsonIgnore]
-    public int ElementSize => ((int)DataType & 0xFF) >> 3;
+    public int ElementSize => DataType.GetElementSize();
+
+    /// <summary>
+    /// Checks if the data type is suitable for the aggregation kind. Statistical aggregations (mean, standard deviation, RMS) of an integer representation lose precision and should use a floating-point data type instead.
+    /// </summary>
+    /// <returns>A warning message if the data type is not suitable, otherwise <see langword="null"/>.</returns>
+    public string? CheckAggregationType()
+    {
+        var isStatistical = Kind is
+            RepresentationKind.Mean or
+            RepresentationKind.MeanPolarDeg or
+            RepresentationKind.Std or
+            RepresentationKind.Rms;
+
+        if (isStatistical && !DataType.IsFloatingPoint())
+            return $"The representation {Id} is of integer type {DataType} which loses precision for the aggregation kind {Kind}. Consider using {NexusDataType.FLOAT64} instead.";
+
+        return default;
+    }
+
+    /// <summary>
+    /// Computes the identifier of an original representation without constructing it.
+    /// </summary>
+    /// <param name="samplePeriod">The sample period.</param>
+    /// <returns>The representation identifier.</returns>
+    public static string GetId(TimeSpan samplePeriod)
+    {
+        return GetId(samplePeriod, RepresentationKind.Original);
+    }
+
+    /// <summary>
+    /// Gets the identifier a representation with the specified sample period and kind would have, e.g. <c>10_ms_mean</c>. The identifier of an original representation has no suffix.
+    /// </summary>
+    /// <param name="samplePeriod">The sample period.</param>
+    /// <param name="kind">The representation kind.</param>
+    /// <returns>The representation identifier.</returns>
+    public static string GetId(TimeSpan samplePeriod, RepresentationKind kind)
+    {
+        var id = samplePeriod.ToUnitString();
+
+        if (kind != RepresentationKind.Original)
+        {
+            var snakeCaseKind = _snakeCaseEvaluator.Replace(kind.ToString(), "_$1").Trim().ToLower();
+            id = $"{id}_{snakeCaseKind}";
+        }
+
+        return id;
+    }
+
+    /// <summary>
+    /// Gets the number of elements of a standard day, e.g. 86400 for a sample period of 1 s. This is useful to plan daily file storage.
+    /// </summary>
+    /// <returns>The number of elements per day.</returns>
+    /// <exception cref="Exception">Thrown when the sample period does not evenly divide a day.</exception>
+    public long GetElementsPerDay()
+    {
+        var elementsPerDay = Math.DivRem(TimeSpan.TicksPerDay, SamplePeriod.Ticks, out var remainder);
+
+        if (remainder != 0 || elementsPerDay == 0)
+            throw new Exception($"The sample period {SamplePeriod.ToUnitString()} does not evenly divide a day.");
+
+        return elementsPerDay;
+    }
+
+    /// <summary>
+    /// Gets a human readable label which combines the sample period and the kind, e.g. <c>1 min (mean)</c> or <c>1 s</c> for an original representation.
+    /// </summary>
+    /// <returns>The label.</returns>
+    public string GetLabel()
+    {
+        var unitString = SamplePeriod.ToUnitString();
+        var period = unitString.Replace('_', ' ');
+
+        if (Kind == RepresentationKind.Original)
+            return period;
+
+        var kindName = Id[(unitString.Length + 1)..].Replace('_', ' ');
+
+        return $"{period} ({kindName})";
+    }
+
+    /// <summary>
+    /// Checks whether this representation can be safely unioned with another representation into one resource. Representations with different IDs are always compatible; representations sharing an ID must not differ in their data type or parameters.
+    /// </summary>
+    /// <param name="other">The other representation.</param>
+    /// <returns>A boolean which indicates if the representations are compatible.</returns>
+    public bool IsCompatibleWith(Representation other)
+    {
+        if (Id != other.Id)
+            return true;
+
+        return DataModelUtilities.AreIdentical(this, other);
+    }
 
     internal Representation DeepCopy()
     {
@@ -126,7 +231,8 @@ public partial record Representation
             dataType: DataType,
             samplePeriod: SamplePeriod,
             parameters: Parameters?.ToDictionary(parameter => parameter.Key, parameter => parameter.Value.Clone()),
-            kind: Kind
+            kind: Kind,
+            properties: Properties?.ToDictionary(property => property.Key, property => property.Value.Clone())
         );
     }
 
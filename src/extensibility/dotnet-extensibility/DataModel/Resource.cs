@@ -92,13 +92,85 @@ public partial record Resource
         }
     }
 
+    /// <summary>
+    /// Gets the representations in display order: original representations first, then the remaining ones by ascending sample period and by kind.
+    /// </summary>
+    /// <returns>The ordered list of representations.</returns>
+    public IReadOnlyList<Representation> GetRepresentationsForDisplay()
+    {
+        if (Representations is null)
+            return [];
+
+        return Representations
+            .OrderBy(representation => representation.Kind != RepresentationKind.Original)
+            .ThenBy(representation => representation.SamplePeriod)
+            .ThenBy(representation => representation.Kind)
+            .ToList();
+    }
+
+    /// <summary>
+    /// Gets the representations as a list of selectable options in display order (see <see cref="GetRepresentationsForDisplay"/>), e.g. <c>("1_min_mean", "1 min (mean)")</c>.
+    /// </summary>
+    /// <returns>The list of representation identifiers and labels.</returns>
+    public IReadOnlyList<(string Id, string Label)> GetRepresentationOptions()
+    {
+        return GetRepresentationsForDisplay()
+            .Select(representation => (representation.Id, representation.GetLabel()))
+            .ToList();
+    }
+
+    /// <summary>
+    /// Ensures that the sample period of each aggregated representation is an integer multiple of the sample period of the original representation. When there are multiple original representations, the one with the smallest sample period is used as the base.
+    /// </summary>
+    /// <exception cref="Exception">Thrown when there is no original representation or when a sample period is not a multiple of the base period.</exception>
+    public void ValidatePeriodHierarchy()
+    {
+        if (Representations is null || !Representations.Any())
+            return;
+
+        var originals = Representations
+            .Where(representation => representation.Kind == RepresentationKind.Original)
+            .ToList();
+
+        if (!originals.Any())
+            throw new Exception($"The resource {Id} has no original representation.");
+
+        var basePeriod = originals.Min(representation => representation.SamplePeriod);
+
+        foreach (var representation in Representations)
+        {
+            if (representation.Kind != RepresentationKind.Original && representation.SamplePeriod.Ticks % basePeriod.Ticks != 0)
+                throw new Exception($"The sample period of representation {representation.Id} of resource {Id} is not a multiple of the original sample period {basePeriod.ToUnitString()}.");
+        }
+    }
+
+    /// <summary>
+    /// Ensures that no representation declares a unit (see <see cref="DataModelExtensions.UnitKey"/>) in its properties which differs from the unit of this resource.
+    /// </summary>
+    /// <exception cref="Exception">Thrown when a representation unit conflicts with the resource unit.</exception>
+    public void ValidateUnitConsistency()
+    {
+        if (Representations is null)
+            return;
+
+        var unit = Properties?.GetStringValue(DataModelExtensions.UnitKey);
+
+        foreach (var representation in Representations)
+        {
+            var representationUnit = representation.Properties?.GetStringValue(DataModelExtensions.UnitKey);
+
+            if (representationUnit is not null && representationUnit != unit)
+                throw new Exception($"The unit {representationUnit} of representation {representation.Id} of resource {Id} conflicts with the resource unit {unit ?? "<none>"}.");
+        }
+    }
+
     internal Resource Merge(Resource resource)
     {
         if (Id != resource.Id)
             throw new ArgumentException("The resources to be merged have different identifiers.");
 
         var mergedProperties = DataModelUtilities.MergeProperties(Properties, resource.Properties);
-        var mergedRepresentations = DataModelUtilities.MergeRepresentations(Representations, resource.Representations);
+        var mergedRepresentations = DataModelUtilities.MergeRepresentations(Id, Representations, resource.Representations);
 
         var merged = resource with
         {
@@ -14,6 +14,7 @@ public record ResourceBuilder
 {
     private readonly string _id;
     private Dictionary<string, JsonElement>? _properties;
+    private List<string>? _strictProblems;
     private List<Representation>? _representations;
 
     /// <summary>
@@ -25,6 +26,25 @@ public record ResourceBuilder
         _id = id;
     }
 
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ResourceBuilder"/> by copying the accumulated properties and representations of another builder. This copy constructor is used by <see langword="with"/> expressions so that a configured builder can serve as a template (e.g. <c>template with { }</c>) without sharing state with its copies.
+    /// </summary>
+    /// <param name="original">The builder to copy.</param>
+    protected ResourceBuilder(ResourceBuilder original)
+    {
+        _id = original._id;
+        _properties = original._properties?.ToDictionary(entry => entry.Key, entry => entry.Value.Clone());
+        _strictProblems = original._strictProblems?.ToList();
+        _representations = original._representations?.ToList();
+        IsStrict = original.IsStrict;
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if properties with well-known keys (e.g. <see cref="DataModelExtensions.GroupsKey"/>) are validated so that an exception is thrown when their value would not round-trip.
+    /// Additionally, overwritten properties and empty property values are collected and reported as an <see cref="AggregateException"/> when building.
+    /// </summary>
+    public bool IsStrict { get; init; }
+
     /// <summary>
     /// Adds a property.
     /// </summary>
@@ -35,7 +55,15 @@ public record ResourceBuilder
     {
         _properties ??= [];
 
-        _properties[key] = JsonSerializer.SerializeToElement(value);
+        var element = JsonSerializer.SerializeToElement(value);
+
+        if (IsStrict)
+        {
+            DataModelUtilities.ValidateWellKnownProperty(key, element);
+            DataModelUtilities.CollectStrictPropertyProblems(_properties, key, element, ref _strictProblems);
+        }
+
+        _properties[key] = element;
 
         return this;
     }
@@ -82,8 +110,17 @@ public record ResourceBuilder
     /// Builds the <see cref="Resource"/>.
     /// </summary>
     /// <returns>The <see cref="Resource"/>.</returns>
+    /// <exception cref="AggregateException">Thrown in strict mode when problems have been detected.</exception>
+    /// <exception cref="Exception">Thrown when the default representation (see <see cref="DataModelExtensions.WithDefaultRepresentation"/>) has not been added to the resource.</exception>
     public Resource Build()
     {
+        DataModelUtilities.ThrowOnStrictProblems(_strictProblems);
+
+        var defaultRepresentationId = _properties?.GetStringValue(DataModelExtensions.DefaultRepresentationKey);
+
+        if (defaultRepresentationId is not null && (_representations is null || !_representations.Any(current => current.Id == defaultRepresentationId)))
+            throw new Exception($"The default representation {defaultRepresentationId} of resource {_id} does not exist.");
+
         return new Resource(_id, _properties, _representations);
     }
 }
\ No newline at end of file
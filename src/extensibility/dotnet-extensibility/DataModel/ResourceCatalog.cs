@@ -18,6 +18,7 @@ public partial record ResourceCatalog
 {
     private string _id = default!;
     private IReadOnlyList<Resource>? _resources;
+    private IReadOnlyList<string>? _childIds;
 
     /// <summary>
     /// Initializes a new instance of the <see cref="ResourceCatalog"/>.
@@ -25,22 +26,116 @@ public partial record ResourceCatalog
     /// <param name="id">The catalog identifier.</param>
     /// <param name="properties">The properties.</param>
     /// <param name="resources">The list of resources.</param>
+    /// <param name="childIds">The list of child catalog identifiers.</param>
     /// <exception cref="ArgumentException">Thrown when the resource identifier is not valid.</exception>
     public ResourceCatalog(
         string id,
         IReadOnlyDictionary<string, JsonElement>? properties = default,
-        IReadOnlyList<Resource>? resources = default)
+        IReadOnlyList<Resource>? resources = default,
+        IReadOnlyList<string>? childIds = default)
     {
         Id = id;
         Properties = properties;
         Resources = resources;
+        ChildIds = childIds;
     }
 
+    /// <summary>
+    /// The root path. It is a valid path (e.g. for catalog registrations) but not a valid resource catalog identifier, i.e. there is no root catalog.
+    /// </summary>
+    public const string RootPath = "/";
+
+    /// <summary>
+    /// The current version of the well-known catalog properties (see <see cref="DataModelExtensions.SchemaVersionKey"/>).
+    /// </summary>
+    public const int CurrentSchemaVersion = 1;
+
+    /* version N => property renames to get from version N to version N + 1 (no well-known property has been renamed so far, which is why the migration is not public yet) */
+    private static readonly Dictionary<int, (string From, string To)[]> _schemaRenames = [];
+
     /// <summary>
     /// Gets a regular expression to validate a resource catalog identifier.
     /// </summary>
     public static Regex ValidIdExpression { get; } = ValidIdExpressionRegex();
 
+    /// <summary>
+    /// Appends a single segment to a resource catalog identifier, e.g. <c>/a</c> and <c>b</c> become <c>/a/b</c>. The parent may also be the <see cref="RootPath"/>.
+    /// </summary>
+    /// <param name="catalogId">The parent catalog identifier or the <see cref="RootPath"/>.</param>
+    /// <param name="segment">The segment to append.</param>
+    /// <returns>The child catalog identifier.</returns>
+    /// <exception cref="ArgumentException">Thrown when the parent catalog identifier or the segment is not valid.</exception>
+    public static string JoinId(string catalogId, string segment)
+    {
+        if (catalogId != RootPath && !ValidIdExpression.IsMatch(catalogId))
+            throw new ArgumentException($"The resource catalog identifier {catalogId} is not valid.");
+
+        // catalog path segments and resource identifiers have the same requirements
+        if (!Resource.ValidIdExpression.IsMatch(segment))
+            throw new ArgumentException($"The resource catalog identifier segment {segment} is not valid.");
+
+        return catalogId == RootPath
+            ? $"{RootPath}{segment}"
+            : $"{catalogId}/{segment}";
+    }
+
+    /// <summary>
+    /// Splits a path like <c>/a/b/temp</c> into a catalog identifier and a resource identifier. Since catalogs may be nested, the longest matching known catalog identifier wins, e.g. <c>/a/b</c> is preferred over <c>/a</c>.
+    /// </summary>
+    /// <param name="path">The path to split.</param>
+    /// <param name="knownCatalogIds">The known catalog identifiers.</param>
+    /// <param name="catalogId">The catalog identifier.</param>
+    /// <param name="resourceId">The resource identifier.</param>
+    /// <returns>True when a known catalog identifier matches and the remainder is a valid resource identifier, otherwise false.</returns>
+    public static bool TrySplitCatalogAndResource(
+        string path,
+        IEnumerable<string> knownCatalogIds,
+        [NotNullWhen(returnValue: true)] out string? catalogId,
+        [NotNullWhen(returnValue: true)] out string? resourceId)
+    {
+        catalogId = default;
+        resourceId = default;
+
+        // catalogs may be nested, so the longest matching catalog identifier wins
+        var matchingCatalogId = knownCatalogIds
+            .Where(current => path.StartsWith(current + "/"))
+            .MaxBy(current => current.Length);
+
+        if (matchingCatalogId is null)
+            return false;
+
+        var remainder = path[(matchingCatalogId.Length + 1)..];
+
+        if (!Resource.ValidIdExpression.IsMatch(remainder))
+            return false;
+
+        catalogId = matchingCatalogId;
+        resourceId = remainder;
+
+        return true;
+    }
+
+    /// <summary>
+    /// Ensures that a resource catalog identifier does not exceed a maximum depth (number of segments, e.g. 3 for <c>/a/b/c</c>) and a maximum length. This allows hosts to enforce sane bounds on identifiers provided by data sources.
+    /// </summary>
+    /// <param name="catalogId">The catalog identifier.</param>
+    /// <param name="maxDepth">The maximum number of segments.</param>
+    /// <param name="maxLength">The maximum number of characters.</param>
+    /// <exception cref="ArgumentException">Thrown when the catalog identifier is not valid or exceeds a limit.</exception>
+    public static void ValidateIdLimits(string catalogId, int maxDepth, int maxLength)
+    {
+        if (!ValidIdExpression.IsMatch(catalogId))
+            throw new ArgumentException($"The resource catalog identifier {catalogId} is not valid.");
+
+        var depth = catalogId.Count(character => character == '/');
+
+        if (depth > maxDepth)
+            throw new ArgumentException($"The depth {depth} of the resource catalog identifier {catalogId} exceeds the maximum depth of {maxDepth}.");
+
+        if (catalogId.Length > maxLength)
+            throw new ArgumentException($"The length {catalogId.Length} of the resource catalog identifier {catalogId} exceeds the maximum length of {maxLength}.");
+    }
+
     private static Regex _matchSingleParametersExpression { get; } = new Regex(@"\s*(.+?)\s*=\s*([^,\)]+)\s*,?", RegexOptions.Compiled);
 
     /// <summary>
@@ -86,6 +181,25 @@ public partial record ResourceCatalog
         }
     }
 
+    /// <summary>
+    /// Gets the list of child catalog identifiers.
+    /// </summary>
+    public IReadOnlyList<string>? ChildIds
+    {
+        get
+        {
+            return _childIds;
+        }
+
+        init
+        {
+            if (value is not null)
+                ValidateChildIds(value);
+
+            _childIds = value;
+        }
+    }
+
     /// <summary>
     /// Merges another catalog with this instance.
     /// </summary>
@@ -99,15 +213,148 @@ public partial record ResourceCatalog
         var mergedProperties = DataModelUtilities.MergeProperties(Properties, catalog.Properties);
         var mergedResources = DataModelUtilities.MergeResources(Resources, catalog.Resources);
 
+        var mergedChildIds = ChildIds is null && catalog.ChildIds is null
+            ? default
+            : (ChildIds ?? []).Union(catalog.ChildIds ?? []).ToList();
+
         var merged = catalog with
         {
             Properties = mergedProperties,
-            Resources = mergedResources
+            Resources = mergedResources,
+            ChildIds = mergedChildIds
         };
 
         return merged;
     }
 
+    /// <summary>
+    /// Migrates the catalog properties to the specified schema version by applying the property renames of each version step (see <see cref="CurrentSchemaVersion"/>). A catalog without a schema version is treated as version 1.
+    /// </summary>
+    /// <param name="targetVersion">The schema version to migrate to.</param>
+    /// <returns>The migrated catalog.</returns>
+    /// <exception cref="ArgumentException">Thrown when the target version is unknown or older than the current version of the catalog.</exception>
+    /// <exception cref="Exception">Thrown when a property is to be renamed but the new key is already in use.</exception>
+    internal ResourceCatalog MigrateProperties(int targetVersion)
+    {
+        var version = this.GetSchemaVersion() ?? 1;
+
+        if (targetVersion < version || targetVersion > CurrentSchemaVersion)
+            throw new ArgumentException($"The catalog {Id} cannot be migrated from schema version {version} to {targetVersion}.");
+
+        return this with
+        {
+            Properties = MigrateProperties(Properties, version, targetVersion, _schemaRenames)
+        };
+    }
+
+    internal static IReadOnlyDictionary<string, JsonElement> MigrateProperties(
+        IReadOnlyDictionary<string, JsonElement>? properties,
+        int version,
+        int targetVersion,
+        IReadOnlyDictionary<int, (string From, string To)[]> schemaRenames)
+    {
+        var migratedProperties = properties?.ToDictionary(entry => entry.Key, entry => entry.Value) ?? [];
+
+        for (; version < targetVersion; version++)
+        {
+            foreach (var (from, to) in schemaRenames.GetValueOrDefault(version) ?? [])
+            {
+                if (!migratedProperties.Remove(from, out var value))
+                    continue;
+
+                if (!migratedProperties.TryAdd(to, value))
+                    throw new Exception($"The property {from} cannot be renamed to {to} because the property {to} already exists.");
+            }
+        }
+
+        migratedProperties[DataModelExtensions.SchemaVersionKey] = JsonSerializer.SerializeToElement(targetVersion);
+
+        return migratedProperties;
+    }
+
+    /// <summary>
+    /// Gets an index which maps each representation identifier to the identifiers of the resources which contain such a representation.
+    /// </summary>
+    /// <returns>The representation identifier index.</returns>
+    public IReadOnlyDictionary<string, IReadOnlyList<string>> GetRepresentationIdIndex()
+    {
+        var index = new Dictionary<string, List<string>>();
+
+        foreach (var resource in Resources ?? [])
+        {
+            foreach (var representation in resource.Representations ?? [])
+            {
+                if (!index.TryGetValue(representation.Id, out var resourceIds))
+                {
+                    resourceIds = [];
+                    index[representation.Id] = resourceIds;
+                }
+
+                resourceIds.Add(resource.Id);
+            }
+        }
+
+        return index.ToDictionary(entry => entry.Key, entry => (IReadOnlyList<string>)entry.Value);
+    }
+
+    /// <summary>
+    /// Gets the distinct representation kinds offered by this catalog, e.g. to summarize its aggregation capabilities.
+    /// </summary>
+    /// <returns>The ordered set of representation kinds.</returns>
+    public SortedSet<RepresentationKind> GetDistinctKinds()
+    {
+        return new SortedSet<RepresentationKind>((Resources ?? [])
+            .SelectMany(resource => resource.Representations ?? [])
+            .Select(representation => representation.Kind));
+    }
+
+    /// <summary>
+    /// Gets the representation parameters used across this catalog, sorted by parameter key. Each key is mapped to the distinct parameter definitions (e.g. <c>{"type":"input-integer"}</c>) found on the representations. This is useful to build parameter filters.
+    /// </summary>
+    /// <returns>The used parameters.</returns>
+    public IReadOnlyDictionary<string, IReadOnlyList<JsonElement>> GetUsedParameters()
+    {
+        var usedParameters = new SortedDictionary<string, List<JsonElement>>(StringComparer.Ordinal);
+
+        var parameters = (Resources ?? [])
+            .SelectMany(resource => resource.Representations ?? [])
+            .SelectMany(representation => representation.Parameters ?? new Dictionary<string, JsonElement>());
+
+        foreach (var (key, definition) in parameters)
+        {
+            if (!usedParameters.TryGetValue(key, out var definitions))
+            {
+                definitions = [];
+                usedParameters[key] = definitions;
+            }
+
+            if (!definitions.Any(current => current.GetRawText() == definition.GetRawText()))
+                definitions.Add(definition);
+        }
+
+        return usedParameters.ToDictionary(entry => entry.Key, entry => (IReadOnlyList<JsonElement>)entry.Value);
+    }
+
+    /// <summary>
+    /// Gets the total number of catalogs in the tree below and including this catalog. Child catalogs are resolved via <paramref name="catalogs"/>, unresolvable child identifiers are ignored.
+    /// </summary>
+    /// <param name="catalogs">A lookup of catalogs by identifier which is used to resolve the child catalogs.</param>
+    /// <returns>The number of catalogs.</returns>
+    public int GetCatalogCount(IReadOnlyDictionary<string, ResourceCatalog> catalogs)
+    {
+        return 1 + GetChildCatalogs(catalogs).Sum(child => child.GetCatalogCount(catalogs));
+    }
+
+    /// <summary>
+    /// Gets the total number of resources in the tree below and including this catalog. Child catalogs are resolved via <paramref name="catalogs"/>, unresolvable child identifiers are ignored.
+    /// </summary>
+    /// <param name="catalogs">A lookup of catalogs by identifier which is used to resolve the child catalogs.</param>
+    /// <returns>The number of resources.</returns>
+    public int GetResourceCount(IReadOnlyDictionary<string, ResourceCatalog> catalogs)
+    {
+        return (Resources?.Count ?? 0) + GetChildCatalogs(catalogs).Sum(child => child.GetResourceCount(catalogs));
+    }
+
     internal bool TryFind(ResourcePathParseResult parseResult, [NotNullWhen(true)] out CatalogItem? catalogItem)
     {
         catalogItem = default;
@@ -192,6 +439,15 @@ public partial record ResourceCatalog
         return catalogItem;
     }
 
+    private IEnumerable<ResourceCatalog> GetChildCatalogs(IReadOnlyDictionary<string, ResourceCatalog> catalogs)
+    {
+        foreach (var childId in ChildIds ?? [])
+        {
+            if (catalogs.TryGetValue(childId, out var child))
+                yield return child;
+        }
+    }
+
     private static void ValidateResources(IReadOnlyList<Resource> resources)
     {
         var uniqueIds = resources
@@ -202,6 +458,18 @@ public partial record ResourceCatalog
             throw new ArgumentException("There are multiple resources with the same identifier.");
     }
 
+    private void ValidateChildIds(IReadOnlyList<string> childIds)
+    {
+        foreach (var childId in childIds)
+        {
+            if (!ValidIdExpression.IsMatch(childId) || !childId.StartsWith(_id + "/"))
+                throw new ArgumentException($"The child catalog identifier {childId} is not valid.");
+        }
+
+        if (childIds.Distinct().Count() != childIds.Count)
+            throw new ArgumentException("There are multiple child catalogs with the same identifier.");
+    }
+
     [GeneratedRegex(@"^(?:\/[a-zA-Z_][a-zA-Z_0-9]*)+$", RegexOptions.Compiled)]
     private static partial Regex ValidIdExpressionRegex();
 }
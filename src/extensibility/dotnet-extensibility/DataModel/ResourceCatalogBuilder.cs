@@ -1,6 +1,7 @@
 ﻿// MIT License
 // Copyright (c) [2024] [nexus-main]
 
+using System.Diagnostics.CodeAnalysis;
 using System.Text.Json;
 
 namespace Nexus.DataModel;
@@ -12,7 +13,9 @@ public record ResourceCatalogBuilder
 {
     private readonly string _id;
     private Dictionary<string, JsonElement>? _properties;
+    private List<string>? _strictProblems;
     private List<Resource>? _resources;
+    private List<string>? _childIds;
 
     /// <summary>
     /// Initializes a new instance of the <see cref="ResourceCatalogBuilder"/>.
@@ -23,6 +26,26 @@ public record ResourceCatalogBuilder
         _id = id;
     }
 
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ResourceCatalogBuilder"/> by copying the accumulated properties, resources and child catalogs of another builder. This copy constructor is used by <see langword="with"/> expressions so that a configured builder can serve as a template (e.g. <c>template with { }</c>) without sharing state with its copies.
+    /// </summary>
+    /// <param name="original">The builder to copy.</param>
+    protected ResourceCatalogBuilder(ResourceCatalogBuilder original)
+    {
+        _id = original._id;
+        _properties = original._properties?.ToDictionary(entry => entry.Key, entry => entry.Value.Clone());
+        _strictProblems = original._strictProblems?.ToList();
+        _resources = original._resources?.ToList();
+        _childIds = original._childIds?.ToList();
+        IsStrict = original.IsStrict;
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if properties with well-known keys (e.g. <see cref="DataModelExtensions.GroupsKey"/>) are validated so that an exception is thrown when their value would not round-trip.
+    /// Additionally, overwritten properties and empty property values are collected and reported as an <see cref="AggregateException"/> when building.
+    /// </summary>
+    public bool IsStrict { get; init; }
+
     /// <summary>
     /// Adds a property.
     /// </summary>
@@ -33,6 +56,12 @@ public record ResourceCatalogBuilder
     {
         _properties ??= [];
 
+        if (IsStrict)
+        {
+            DataModelUtilities.ValidateWellKnownProperty(key, value);
+            DataModelUtilities.CollectStrictPropertyProblems(_properties, key, value, ref _strictProblems);
+        }
+
         _properties[key] = value;
 
         return this;
@@ -48,7 +77,15 @@ public record ResourceCatalogBuilder
     {
         _properties ??= [];
 
-        _properties[key] = JsonSerializer.SerializeToElement(value);
+        var element = JsonSerializer.SerializeToElement(value);
+
+        if (IsStrict)
+        {
+            DataModelUtilities.ValidateWellKnownProperty(key, element);
+            DataModelUtilities.CollectStrictPropertyProblems(_properties, key, element, ref _strictProblems);
+        }
+
+        _properties[key] = element;
 
         return this;
     }
@@ -91,12 +128,74 @@ public record ResourceCatalogBuilder
         return this;
     }
 
+    /// <summary>
+    /// Tries to add a list of <see cref="Resource"/>. Each resource identifier is checked against the already added resources and the preceding resources of the list. If a duplicate is found, no resource is added.
+    /// </summary>
+    /// <param name="resources">The list of <see cref="Resource"/>.</param>
+    /// <param name="duplicateId">The identifier of the first duplicate resource if any.</param>
+    /// <returns>A boolean which indicates if the resources have been added.</returns>
+    public bool TryAddResources(IEnumerable<Resource> resources, [NotNullWhen(false)] out string? duplicateId)
+    {
+        _resources ??= [];
+
+        var resourceIds = _resources
+            .Select(resource => resource.Id)
+            .ToHashSet();
+
+        var resourcesToAdd = resources.ToList();
+
+        foreach (var resource in resourcesToAdd)
+        {
+            if (!resourceIds.Add(resource.Id))
+            {
+                duplicateId = resource.Id;
+                return false;
+            }
+        }
+
+        _resources.AddRange(resourcesToAdd);
+        duplicateId = default;
+
+        return true;
+    }
+
+    /// <summary>
+    /// Declares a child catalog.
+    /// </summary>
+    /// <param name="childId">The identifier of the child catalog.</param>
+    /// <returns>The resource catalog builder.</returns>
+    public ResourceCatalogBuilder AddChildId(string childId)
+    {
+        _childIds ??= [];
+
+        _childIds.Add(childId);
+
+        return this;
+    }
+
+    /// <summary>
+    /// Declares a list of child catalogs.
+    /// </summary>
+    /// <param name="childIds">The identifiers of the child catalogs.</param>
+    /// <returns>The resource catalog builder.</returns>
+    public ResourceCatalogBuilder AddChildIds(params string[] childIds)
+    {
+        _childIds ??= [];
+
+        _childIds.AddRange(childIds);
+
+        return this;
+    }
+
     /// <summary>
     /// Builds the <see cref="ResourceCatalog"/>.
     /// </summary>
     /// <returns>The <see cref="ResourceCatalog"/>.</returns>
+    /// <exception cref="AggregateException">Thrown in strict mode when problems have been detected.</exception>
     public ResourceCatalog Build()
     {
-        return new ResourceCatalog(_id, _properties, _resources);
+        DataModelUtilities.ThrowOnStrictProblems(_strictProblems);
+
+        return new ResourceCatalog(_id, _properties, _resources, _childIds);
     }
 }
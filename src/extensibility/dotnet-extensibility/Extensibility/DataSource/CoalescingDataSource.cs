@@ -0,0 +1,415 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using Microsoft.Extensions.Logging;
+using Nexus.DataModel;
+using System.Buffers;
+
+namespace Nexus.Extensibility;
+
+/// <summary>
+/// A data source which wraps another data source and coalesces concurrent reads of the same catalog item and time period so that they share a single underlying read. Read requests are considered identical when their catalog items (see <see cref="CatalogItem.Equals(CatalogItem?)"/>), data types, time periods and <see cref="ReadDataHandler"/> instances match, i.e. only callers which share a read data handler are coalesced. Requests which are not already in flight are forwarded to the wrapped data source as a single batch. Requests for a data type which the wrapped data source cannot provide (see <see cref="IDataSource.CanProvideDataType"/>) are read in the native data type and converted afterwards. The shared read is only cancelled when all of its callers have cancelled. Its progress is forwarded to all callers. Disposing this data source cancels all shared reads and disposes the wrapped data source once they have finished.
+/// </summary>
+/// <param name="dataSource">The data source to wrap.</param>
+public class CoalescingDataSource(IDataSource dataSource) : IDataSource, IDisposable
+{
+    private readonly IDataSource _dataSource = dataSource;
+    private readonly Dictionary<ReadKey, SharedItem> _inFlightReads = [];
+    private readonly HashSet<SharedRead> _runningReads = [];
+
+    private static readonly ByteOrder _byteOrder = BitConverter.IsLittleEndian
+        ? ByteOrder.LittleEndian
+        : ByteOrder.BigEndian;
+
+    /// <inheritdoc />
+    public Task SetContextAsync(
+        DataSourceContext context,
+        ILogger logger,
+        CancellationToken cancellationToken)
+    {
+        return _dataSource.SetContextAsync(context, logger, cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public Task<CatalogRegistration[]> GetCatalogRegistrationsAsync(
+        string path,
+        CancellationToken cancellationToken)
+    {
+        return _dataSource.GetCatalogRegistrationsAsync(path, cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public IAsyncEnumerable<CatalogRegistration> StreamCatalogRegistrationsAsync(
+        string path,
+        CancellationToken cancellationToken)
+    {
+        return _dataSource.StreamCatalogRegistrationsAsync(path, cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public Task<ResourceCatalog> GetCatalogAsync(
+        string catalogId,
+        CancellationToken cancellationToken)
+    {
+        return _dataSource.GetCatalogAsync(catalogId, cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public Task<(DateTime Begin, DateTime End)> GetTimeRangeAsync(
+        string catalogId,
+        CancellationToken cancellationToken)
+    {
+        return _dataSource.GetTimeRangeAsync(catalogId, cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public Task<double> GetAvailabilityAsync(
+        string catalogId,
+        DateTime begin,
+        DateTime end,
+        CancellationToken cancellationToken)
+    {
+        return _dataSource.GetAvailabilityAsync(catalogId, begin, end, cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public Task<DateTime?> GetLastModifiedAsync(
+        string catalogId,
+        CancellationToken cancellationToken)
+    {
+        return _dataSource.GetLastModifiedAsync(catalogId, cancellationToken);
+    }
+
+    /// <inheritdoc />
+    public bool CanResample(
+        string catalogId,
+        string resourceId)
+    {
+        return _dataSource.CanResample(catalogId, resourceId);
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if the data source is able to fill the data buffer in the requested data type. This is always the case because data types which the wrapped data source cannot provide are converted by this data source.
+    /// </summary>
+    /// <param name="catalogId">The catalog identifier.</param>
+    /// <param name="resourceId">The resource identifier.</param>
+    /// <param name="dataType">The requested data type.</param>
+    /// <returns>Always <see langword="true"/>.</returns>
+    public bool CanProvideDataType(
+        string catalogId,
+        string resourceId,
+        NexusDataType dataType)
+    {
+        return true;
+    }
+
+    /// <inheritdoc />
+    public async Task ReadAsync(
+        DateTime begin,
+        DateTime end,
+        ReadRequest[] requests,
+        ReadDataHandler readData,
+        IProgress<double> progress,
+        CancellationToken cancellationToken)
+    {
+        var sharedItems = new SharedItem[requests.Length];
+        var requestProgress = new double[requests.Length];
+        var newRead = default(SharedRead);
+
+        var readDataTypes = requests
+            .Select(GetReadDataType)
+            .ToArray();
+
+        lock (_inFlightReads)
+        {
+            ObjectDisposedException.ThrowIf(_disposedValue, this);
+
+            for (int i = 0; i < requests.Length; i++)
+            {
+                var request = requests[i];
+                var key = new ReadKey(request.CatalogItem, request.DataType, request.Quality.HasValue, begin, end, readData);
+
+                if (!_inFlightReads.TryGetValue(key, out var sharedItem))
+                {
+                    newRead ??= new SharedRead();
+                    sharedItem = new SharedItem(newRead, newRead.AddRequest(request, readDataTypes[i]));
+                    newRead.Keys.Add(key);
+                    _inFlightReads[key] = sharedItem;
+                }
+
+                sharedItem.Read.WaiterCount++;
+                sharedItems[i] = sharedItem;
+            }
+
+            /* requests which are not already in flight are forwarded as a single batch */
+            if (newRead is not null)
+            {
+                _runningReads.Add(newRead);
+                newRead.ReadTask = ReadCoreAsync(newRead, begin, end, readData);
+            }
+        }
+
+        void reportProgress(int index, double value)
+        {
+            double totalProgress;
+
+            lock (requestProgress)
+            {
+                requestProgress[index] = value;
+                totalProgress = requestProgress.Average();
+            }
+
+            progress.Report(totalProgress);
+        }
+
+        var tasks = requests.Select(async (request, i) =>
+        {
+            var sharedItem = sharedItems[i];
+            var sharedRequest = sharedItem.Request;
+            void progressHandler(double value) => reportProgress(i, value);
+
+            sharedItem.Read.ProgressChanged += progressHandler;
+
+            try
+            {
+                await sharedItem.Read.Completion.Task.WaitAsync(cancellationToken);
+
+                if (sharedRequest.DataType == request.DataType)
+                    sharedRequest.Data.CopyTo(request.Data);
+
+                else
+                    ExtensibilityUtilities.ConvertInto(request.Data.Span, sharedRequest.Data.Span, sharedRequest.DataType, request.DataType, _byteOrder);
+
+                sharedRequest.Status.CopyTo(request.Status);
+
+                if (request.Quality.HasValue)
+                    sharedRequest.Quality!.Value.CopyTo(request.Quality.Value);
+
+                if (sharedRequest.CoveredRange is { } coveredRange)
+                    request.ReportCoveredRange(coveredRange.Begin, coveredRange.End);
+            }
+            finally
+            {
+                sharedItem.Read.ProgressChanged -= progressHandler;
+                Release(sharedItem.Read);
+            }
+
+            reportProgress(i, 1.0);
+        });
+
+        await Task.WhenAll(tasks);
+    }
+
+    private NexusDataType GetReadDataType(ReadRequest request)
+    {
+        var catalogItem = request.CatalogItem;
+
+        return request.DataType == catalogItem.Representation.DataType ||
+            _dataSource.CanProvideDataType(catalogItem.Catalog.Id, catalogItem.Resource.Id, request.DataType)
+                ? request.DataType
+                : catalogItem.Representation.DataType;
+    }
+
+    private async Task ReadCoreAsync(
+        SharedRead sharedRead,
+        DateTime begin,
+        DateTime end,
+        ReadDataHandler readData)
+    {
+        /* the shared read must not run synchronously while the lock is held */
+        await Task.Yield();
+
+        try
+        {
+            await _dataSource.ReadAsync(
+                begin,
+                end,
+                [.. sharedRead.Requests],
+                readData,
+                sharedRead,
+                sharedRead.CancellationTokenSource.Token);
+
+            sharedRead.Completion.SetResult();
+        }
+        catch (OperationCanceledException) when (sharedRead.CancellationTokenSource.IsCancellationRequested)
+        {
+            sharedRead.Completion.SetCanceled();
+        }
+        catch (Exception ex)
+        {
+            sharedRead.Completion.SetException(ex);
+        }
+        finally
+        {
+            Complete(sharedRead);
+        }
+    }
+
+    private void Complete(SharedRead sharedRead)
+    {
+        lock (_inFlightReads)
+        {
+            sharedRead.IsCompleted = true;
+            _runningReads.Remove(sharedRead);
+            RemoveKeys(sharedRead);
+
+            /* all waiters are gone, i.e. nobody reads the buffers or observes the exception anymore */
+            if (sharedRead.WaiterCount == 0)
+            {
+                _ = sharedRead.Completion.Task.Exception;
+                sharedRead.Dispose();
+            }
+        }
+    }
+
+    private void Release(SharedRead sharedRead)
+    {
+        lock (_inFlightReads)
+        {
+            sharedRead.WaiterCount--;
+
+            if (sharedRead.WaiterCount > 0)
+                return;
+
+            if (sharedRead.IsCompleted)
+            {
+                sharedRead.Dispose();
+            }
+
+            /* every waiter has cancelled, so the shared read is not needed anymore */
+            else
+            {
+                RemoveKeys(sharedRead);
+                sharedRead.CancellationTokenSource.Cancel();
+            }
+        }
+    }
+
+    private void RemoveKeys(SharedRead sharedRead)
+    {
+        foreach (var key in sharedRead.Keys)
+        {
+            if (_inFlightReads.TryGetValue(key, out var sharedItem) && sharedItem.Read == sharedRead)
+                _inFlightReads.Remove(key);
+        }
+    }
+
+    private record ReadKey(CatalogItem CatalogItem, NexusDataType DataType, bool HasQuality, DateTime Begin, DateTime End, ReadDataHandler? ReadData);
+
+    private record SharedItem(SharedRead Read, ReadRequest Request);
+
+    private class SharedRead : IProgress<double>, IDisposable
+    {
+        private readonly List<IMemoryOwner<byte>> _owners = [];
+
+        public event Action<double>? ProgressChanged;
+
+        public List<ReadKey> Keys { get; } = [];
+
+        public List<ReadRequest> Requests { get; } = [];
+
+        public Task ReadTask { get; set; } = Task.CompletedTask;
+
+        public CancellationTokenSource CancellationTokenSource { get; } = new();
+
+        public TaskCompletionSource Completion { get; } = new(TaskCreationOptions.RunContinuationsAsynchronously);
+
+        public int WaiterCount { get; set; }
+
+        public bool IsCompleted { get; set; }
+
+        public ReadRequest AddRequest(ReadRequest request, NexusDataType readDataType)
+        {
+            var elementCount = request.Status.Length;
+
+            var sharedRequest = request with
+            {
+                Data = Rent(elementCount * readDataType.GetElementSize()),
+                RequestedDataType = readDataType == request.CatalogItem.Representation.DataType
+                    ? default(NexusDataType?)
+                    : readDataType,
+                Status = Rent(request.Status.Length),
+                Quality = request.Quality.HasValue ? Rent(request.Quality.Value.Length) : default(Memory<byte>?)
+            };
+
+            Requests.Add(sharedRequest);
+
+            return sharedRequest;
+        }
+
+        public void Report(double value)
+        {
+            ProgressChanged?.Invoke(value);
+        }
+
+        public void Dispose()
+        {
+            foreach (var owner in _owners)
+            {
+                owner.Dispose();
+            }
+
+            _owners.Clear();
+            CancellationTokenSource.Dispose();
+        }
+
+        private Memory<byte> Rent(int length)
+        {
+            var owner = MemoryPool<byte>.Shared.Rent(length);
+            var memory = owner.Memory[..length];
+
+            memory.Span.Clear();
+            _owners.Add(owner);
+
+            return memory;
+        }
+    }
+
+    #region IDisposable
+
+    private bool _disposedValue;
+
+    /// <summary>
+    /// Cancels all shared reads, waits for them to finish and disposes the wrapped data source.
+    /// </summary>
+    /// <param name="disposing">A boolean which indicates if managed resources are to be disposed.</param>
+    protected virtual void Dispose(bool disposing)
+    {
+        if (!_disposedValue)
+        {
+            if (disposing)
+            {
+                Task[] readTasks;
+
+                lock (_inFlightReads)
+                {
+                    _disposedValue = true;
+
+                    foreach (var sharedRead in _runningReads)
+                    {
+                        sharedRead.CancellationTokenSource.Cancel();
+                    }
+
+                    readTasks = _runningReads
+                        .Select(sharedRead => sharedRead.ReadTask)
+                        .ToArray();
+                }
+
+                /* the wrapped data source must not be disposed while it is still reading */
+                Task.WaitAll(readTasks);
+                (_dataSource as IDisposable)?.Dispose();
+            }
+
+            _disposedValue = true;
+        }
+    }
+
+    /// <inheritdoc />
+    public void Dispose()
+    {
+        Dispose(disposing: true);
+        GC.SuppressFinalize(this);
+    }
+
+    #endregion
+}
@@ -0,0 +1,232 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using Nexus.DataModel;
+
+namespace Nexus.Extensibility;
+
+/// <summary>
+/// The result of a single conformance check.
+/// </summary>
+/// <param name="Name">The name of the check.</param>
+/// <param name="Passed">A boolean which indicates if the check has passed.</param>
+/// <param name="Message">An optional message which explains why the check has failed.</param>
+public record ConformanceCheck(string Name, bool Passed, string? Message = default);
+
+/// <summary>
+/// The result of a conformance run.
+/// </summary>
+/// <param name="Checks">The list of checks.</param>
+public record ConformanceReport(IReadOnlyList<ConformanceCheck> Checks)
+{
+    /// <summary>
+    /// Gets a boolean which indicates if all checks have passed.
+    /// </summary>
+    public bool Passed => Checks.All(check => check.Passed);
+}
+
+/// <summary>
+/// A conformance suite which data source authors can run against their implementation.
+/// </summary>
+public static class DataSourceConformance
+{
+    private const int ELEMENT_COUNT = 10;
+
+    /// <summary>
+    /// Exercises the members of a data source and validates the results. The context of the data source must have been set before.
+    /// </summary>
+    /// <param name="dataSource">The data source to check.</param>
+    /// <param name="catalogId">The identifier of a catalog provided by the data source.</param>
+    /// <param name="cancellationToken">A token to cancel the current operation.</param>
+    /// <returns>The conformance report.</returns>
+    public static async Task<ConformanceReport> RunAsync(
+        IDataSource dataSource,
+        string catalogId,
+        CancellationToken cancellationToken)
+    {
+        var checks = new List<ConformanceCheck>();
+
+        /* catalog registrations */
+        checks.Add(await RunCheckAsync("catalog-registrations", async () =>
+        {
+            var catalogRegistrations = await dataSource.GetCatalogRegistrationsAsync("/", cancellationToken);
+
+            return catalogRegistrations.Select(registration => registration.Path).Distinct().Count() == catalogRegistrations.Length
+                ? default
+                : "The catalog registrations contain duplicate paths.";
+        }));
+
+        /* catalog */
+        ResourceCatalog? catalog = default;
+
+        checks.Add(await RunCheckAsync("catalog", async () =>
+        {
+            catalog = await dataSource.GetCatalogAsync(catalogId, cancellationToken);
+
+            return catalog.Id == catalogId
+                ? default
+                : $"The identifier {catalog.Id} of the returned catalog does not match the requested identifier {catalogId}.";
+        }));
+
+        /* time range */
+        var begin = DateTime.MinValue;
+        var end = DateTime.MaxValue;
+        var timeRangeBegin = begin;
+        var timeRangeEnd = end;
+
+        checks.Add(await RunCheckAsync("time-range", async () =>
+        {
+            (begin, end) = await dataSource.GetTimeRangeAsync(catalogId, cancellationToken);
+            (timeRangeBegin, timeRangeEnd) = (begin, end);
+
+            return begin <= end
+                ? default
+                : $"The begin {begin} of the time range is greater than its end {end}.";
+        }));
+
+        if (begin == DateTime.MinValue || begin > end)
+            begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+
+        /* availability */
+        checks.Add(await RunCheckAsync("availability", async () =>
+        {
+            var availability = await dataSource.GetAvailabilityAsync(catalogId, begin, begin.AddDays(1), cancellationToken);
+
+            return double.IsNaN(availability) || (0 <= availability && availability <= 1)
+                ? default
+                : $"The availability {availability} is not within the range [0, 1].";
+        }));
+
+        /* read */
+        var resource = catalog?.Resources?.FirstOrDefault(current => current.Representations is not null && current.Representations.Any());
+
+        if (catalog is null || resource is null)
+        {
+            checks.Add(new ConformanceCheck("read", false, "There is no resource with a representation to read."));
+        }
+
+        else
+        {
+            var representation = resource.Representations![0];
+            var samplePeriod = representation.SamplePeriod;
+
+            /* an unbounded time range (see SimpleDataSource) is not checked */
+            checks.Add(RunCheck("time-range-alignment", () =>
+                IsAlignedOrUnbounded(timeRangeBegin, samplePeriod) && IsAlignedOrUnbounded(timeRangeEnd, samplePeriod)
+                    ? default
+                    : $"The time range {timeRangeBegin:o} - {timeRangeEnd:o} is not aligned to the sample period {samplePeriod.ToUnitString()} of the representation {representation.Id}."));
+
+            var alignedBegin = new DateTime(begin.Ticks - begin.Ticks % samplePeriod.Ticks, DateTimeKind.Utc);
+            var alignedEnd = alignedBegin + ELEMENT_COUNT * samplePeriod;
+
+            var catalogItem = new CatalogItem(
+                catalog with { Resources = default },
+                resource with { Representations = default },
+                representation,
+                Parameters: default);
+
+            using var readRequestManager = new ReadRequestManager(catalogItem, ELEMENT_COUNT);
+            var request = readRequestManager.Request;
+            var progress = new RecordingProgress();
+
+            checks.Add(await RunCheckAsync("read", async () =>
+            {
+                await dataSource.ReadAsync(
+                    alignedBegin,
+                    alignedEnd,
+                    [request],
+                    (_, _, _, _, _) => throw new NotSupportedException("Reading data from Nexus is not supported during a conformance run."),
+                    progress,
+                    cancellationToken);
+
+                return default;
+            }));
+
+            checks.Add(RunCheck("read-covered-range", () =>
+            {
+                if (request.CoveredRange is not { } coveredRange)
+                    return default;
+
+                var (coveredBegin, coveredEnd) = coveredRange;
+
+                if (coveredBegin < alignedBegin || coveredEnd > alignedEnd)
+                    return $"The covered range {coveredBegin:o} - {coveredEnd:o} exceeds the requested period {alignedBegin:o} - {alignedEnd:o}.";
+
+                if (coveredBegin.Ticks % samplePeriod.Ticks != 0 || coveredEnd.Ticks % samplePeriod.Ticks != 0)
+                    return $"The covered range {coveredBegin:o} - {coveredEnd:o} is not aligned to the sample period {samplePeriod.ToUnitString()}.";
+
+                return default;
+            }));
+
+            checks.Add(RunCheck("read-status", () =>
+                request.Status.Span.IndexOfAnyExcept((byte)0, (byte)1) == -1
+                    ? default
+                    : "The status buffer contains values other than 0 and 1."));
+
+            checks.Add(RunCheck("read-status-consistency", () =>
+            {
+                var data = request.Data.Span;
+                var status = request.Status.Span;
+                var elementSize = representation.ElementSize;
+
+                for (int i = 0; i < status.Length; i++)
+                {
+                    if (status[i] == 1 && double.IsNaN(representation.DataType.DecodeDouble(data[(i * elementSize)..])))
+                        return $"The value at index {i} is marked as valid but is NaN.";
+                }
+
+                return default;
+            }));
+
+            checks.Add(RunCheck("read-progress", () =>
+                progress.Values.All(value => 0 <= value && value <= 1)
+                    ? default
+                    : "The reported progress is not within the range [0, 1]."));
+        }
+
+        return new ConformanceReport(checks);
+    }
+
+    private static bool IsAlignedOrUnbounded(DateTime dateTime, TimeSpan samplePeriod)
+    {
+        return dateTime == DateTime.MinValue ||
+            dateTime == DateTime.MaxValue ||
+            dateTime.Ticks % samplePeriod.Ticks == 0;
+    }
+
+    private static async Task<ConformanceCheck> RunCheckAsync(string name, Func<Task<string?>> check)
+    {
+        try
+        {
+            var message = await check();
+            return new ConformanceCheck(name, message is null, message);
+        }
+        catch (Exception ex)
+        {
+            return new ConformanceCheck(name, false, ex.Message);
+        }
+    }
+
+    private static ConformanceCheck RunCheck(string name, Func<string?> check)
+    {
+        try
+        {
+            var message = check();
+            return new ConformanceCheck(name, message is null, message);
+        }
+        catch (Exception ex)
+        {
+            return new ConformanceCheck(name, false, ex.Message);
+        }
+    }
+
+    private class RecordingProgress : IProgress<double>
+    {
+        public List<double> Values { get; } = [];
+
+        public void Report(double value)
+        {
+            Values.Add(value);
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using System.Text.Json;
+
+namespace Nexus.Extensibility;
+
+/// <summary>
+/// A data source context builder simplifies building a data source context, e.g. in tests.
+/// </summary>
+public record DataSourceContextBuilder
+{
+    private readonly IReadOnlyDictionary<string, JsonElement>? _sourceConfiguration;
+    private Uri? _resourceLocator;
+    private IReadOnlyDictionary<string, JsonElement>? _systemConfiguration;
+    private IReadOnlyDictionary<string, JsonElement>? _requestConfiguration;
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="DataSourceContextBuilder"/>.
+    /// </summary>
+    /// <param name="sourceConfiguration">The source configuration.</param>
+    public DataSourceContextBuilder(IReadOnlyDictionary<string, JsonElement>? sourceConfiguration)
+    {
+        _sourceConfiguration = sourceConfiguration;
+    }
+
+    /// <summary>
+    /// Sets the resource locator.
+    /// </summary>
+    /// <param name="resourceLocator">The URL which points to the data.</param>
+    /// <returns>The data source context builder.</returns>
+    public DataSourceContextBuilder WithResourceLocator(Uri resourceLocator)
+    {
+        _resourceLocator = resourceLocator;
+
+        return this;
+    }
+
+    /// <summary>
+    /// Sets the system configuration.
+    /// </summary>
+    /// <param name="systemConfiguration">The system configuration.</param>
+    /// <returns>The data source context builder.</returns>
+    public DataSourceContextBuilder WithSystemConfiguration(IReadOnlyDictionary<string, JsonElement> systemConfiguration)
+    {
+        _systemConfiguration = systemConfiguration;
+
+        return this;
+    }
+
+    /// <summary>
+    /// Sets the request configuration.
+    /// </summary>
+    /// <param name="requestConfiguration">The request configuration.</param>
+    /// <returns>The data source context builder.</returns>
+    public DataSourceContextBuilder WithRequestConfiguration(IReadOnlyDictionary<string, JsonElement> requestConfiguration)
+    {
+        _requestConfiguration = requestConfiguration;
+
+        return this;
+    }
+
+    /// <summary>
+    /// Builds the <see cref="DataSourceContext"/>.
+    /// </summary>
+    /// <returns>The <see cref="DataSourceContext"/>.</returns>
+    public DataSourceContext Build()
+    {
+        return new DataSourceContext(
+            ResourceLocator: _resourceLocator,
+            SystemConfiguration: _systemConfiguration,
+            SourceConfiguration: _sourceConfiguration,
+            RequestConfiguration: _requestConfiguration);
+    }
+}
@@ -3,6 +3,7 @@
 
 using Nexus.DataModel;
 using System.Buffers;
+using System.Diagnostics;
 using System.Text.Json;
 
 namespace Nexus.Extensibility;
@@ -18,7 +19,39 @@ public record DataSourceContext(
     Uri? ResourceLocator,
     IReadOnlyDictionary<string, JsonElement>? SystemConfiguration,
     IReadOnlyDictionary<string, JsonElement>? SourceConfiguration,
-    IReadOnlyDictionary<string, JsonElement>? RequestConfiguration);
+    IReadOnlyDictionary<string, JsonElement>? RequestConfiguration)
+{
+    /// <summary>
+    /// Ensures that the context satisfies the preconditions of a data source.
+    /// </summary>
+    /// <param name="requiredRequestKeys">The keys which must be present in the request configuration.</param>
+    /// <param name="requiresResourceLocator">A boolean which indicates if the resource locator must be set.</param>
+    /// <exception cref="ArgumentException">Thrown when the context is not consistent.</exception>
+    public void Validate(IEnumerable<string> requiredRequestKeys, bool requiresResourceLocator = false)
+    {
+        if (requiresResourceLocator && ResourceLocator is null)
+            throw new ArgumentException("The resource locator is required but has not been set.");
+
+        foreach (var key in requiredRequestKeys)
+        {
+            if (RequestConfiguration is null || !RequestConfiguration.ContainsKey(key))
+                throw new ArgumentException($"The required request configuration key {key} is missing.");
+        }
+    }
+
+    /// <summary>
+    /// Ensures that all request configuration keys follow the identifier grammar (see <see cref="Resource.ValidIdExpression"/>), optionally namespaced with dots (e.g. <c>my_source.user</c>). Data sources which want to catch configuration typos early can opt in to this validation.
+    /// </summary>
+    /// <exception cref="ArgumentException">Thrown when a request configuration key is not valid.</exception>
+    public void ValidateRequestKeys()
+    {
+        foreach (var key in RequestConfiguration?.Keys ?? Enumerable.Empty<string>())
+        {
+            if (!key.Split('.').All(segment => Resource.ValidIdExpression.IsMatch(segment)))
+                throw new ArgumentException($"The request configuration key {key} is not valid.");
+        }
+    }
+}
 
 /// <summary>
 /// A read request.
@@ -29,17 +62,98 @@ public record DataSourceContext(
 public record ReadRequest(
     CatalogItem CatalogItem,
     Memory<byte> Data,
-    Memory<byte> Status);
+    Memory<byte> Status)
+{
+    /// <summary>
+    /// Gets the optional data type in which the data buffer is to be filled. When not set, the data type of the representation is used. It is only set for data sources which have declared support for it (see <see cref="IDataSource.CanProvideDataType"/>), otherwise the data is read in the native data type and converted afterwards.
+    /// </summary>
+    public NexusDataType? RequestedDataType { get; init; }
+
+    /// <summary>
+    /// Gets the optional quality buffer. In contrast to the binary <see cref="Status"/>, it contains a quality code (0-255) per sample. Use <see cref="ExtensibilityUtilities.DeriveStatusFromQuality"/> to derive the status from it.
+    /// </summary>
+    public Memory<byte>? Quality { get; init; }
+
+    /// <summary>
+    /// Gets the period which is actually covered by the data buffer (see <see cref="ReportCoveredRange"/>). When not set, the requested period is assumed. It is an output of <see cref="IDataSource.ReadAsync"/> and therefore neither part of the equality of read requests nor copied by <see langword="with"/> expressions.
+    /// </summary>
+    public (DateTime Begin, DateTime End)? CoveredRange { get; private set; }
+
+    /// <summary>
+    /// Gets the data type in which the data buffer is to be filled.
+    /// </summary>
+    public NexusDataType DataType => RequestedDataType ?? CatalogItem.Representation.DataType;
+
+    /// <summary>
+    /// Gets the number of elements of the data buffer.
+    /// </summary>
+    public int ElementCount
+    {
+        get
+        {
+            var elementSize = DataType.GetElementSize();
+
+            Debug.Assert(Data.Length % elementSize == 0, "The data buffer length must be a multiple of the element size.");
+
+            return Data.Length / elementSize;
+        }
+    }
+
+    /// <summary>
+    /// Initializes a new instance of the <see cref="ReadRequest"/> as a copy of another read request. The covered range is not copied.
+    /// </summary>
+    /// <param name="original">The read request to copy.</param>
+    protected ReadRequest(ReadRequest original)
+    {
+        CatalogItem = original.CatalogItem;
+        Data = original.Data;
+        Status = original.Status;
+        RequestedDataType = original.RequestedDataType;
+        Quality = original.Quality;
+    }
+
+    /// <summary>
+    /// Reports the period which is actually covered by the data buffer. A data source calls it during <see cref="IDataSource.ReadAsync"/> when it is only able to provide data for a part of the requested period (e.g. because it is clamped to the available data).
+    /// </summary>
+    /// <param name="begin">The beginning of the covered period.</param>
+    /// <param name="end">The end of the covered period.</param>
+    /// <exception cref="ArgumentException">Thrown when the end is before the beginning.</exception>
+    public void ReportCoveredRange(DateTime begin, DateTime end)
+    {
+        if (end < begin)
+            throw new ArgumentException("The end of the covered range must not be before its beginning.");
+
+        CoveredRange = (begin, end);
+    }
+
+    /// <inheritdoc />
+    public virtual bool Equals(ReadRequest? other)
+    {
+        return other is not null &&
+            EqualityContract == other.EqualityContract &&
+            CatalogItem == other.CatalogItem &&
+            Data.Equals(other.Data) &&
+            Status.Equals(other.Status) &&
+            RequestedDataType == other.RequestedDataType &&
+            Nullable.Equals(Quality, other.Quality);
+    }
+
+    /// <inheritdoc />
+    public override int GetHashCode()
+    {
+        return HashCode.Combine(EqualityContract, CatalogItem, Data, Status, RequestedDataType, Quality);
+    }
+}
 
 /// <summary>
-/// Reads the requested data.
+/// Reads the requested data. The handler is asynchronous and fallible: a failed read is reported by a faulted task, i.e. awaiting the task rethrows the exception so that it propagates through <see cref="IDataSource.ReadAsync"/>.
 /// </summary>
 /// <param name="resourcePath">The path to the resource data to stream.</param>
 /// <param name="begin">Start date/time.</param>
 /// <param name="end">End date/time.</param>
 /// <param name="buffer">The buffer to read to the data into.</param>
 /// <param name="cancellationToken">A cancellation token.</param>
-/// <returns></returns>
+/// <returns>The task.</returns>
 public delegate Task ReadDataHandler(
     string resourcePath,
     DateTime begin,
@@ -47,6 +161,82 @@ public delegate Task ReadDataHandler(
     Memory<double> buffer,
     CancellationToken cancellationToken);
 
+/// <summary>
+/// Specifies how two status buffers are merged.
+/// </summary>
+public enum StatusMergeMode
+{
+    /// <summary>
+    /// A sample is valid only if it is valid in both buffers.
+    /// </summary>
+    And,
+
+    /// <summary>
+    /// A sample is valid if it is valid in either buffer.
+    /// </summary>
+    Or
+}
+
+/// <summary>
+/// Options to control how <see cref="IDataSource.ReadStreamAsync"/> splits a read into chunks.
+/// </summary>
+public record ReadStreamOptions
+{
+    /// <summary>
+    /// The default number of elements per chunk.
+    /// </summary>
+    public const int DefaultChunkSize = 100_000;
+
+    private readonly int _chunkSize = DefaultChunkSize;
+
+    /// <summary>
+    /// Gets the maximum number of elements per chunk. Memory-constrained hosts may choose small chunks while others benefit from large chunks.
+    /// </summary>
+    public int ChunkSize
+    {
+        get => _chunkSize;
+
+        init
+        {
+            if (value <= 0)
+                throw new ArgumentException("The chunk size must be greater than zero.");
+
+            _chunkSize = value;
+        }
+    }
+
+    /// <summary>
+    /// Gets the optional data type in which the chunks are to be filled. When not set, the data type of the representation is used. The data is read in the native data type and converted afterwards unless the data source has declared support for the requested data type (see <see cref="IDataSource.CanProvideDataType"/>).
+    /// </summary>
+    public NexusDataType? RequestedDataType { get; init; }
+}
+
+/// <summary>
+/// A chunk of a streamed read operation.
+/// </summary>
+/// <param name="Begin">The beginning of the chunk.</param>
+/// <param name="End">The end of the chunk.</param>
+/// <param name="Request">The read request which contains the data and status buffers of the chunk. The buffers are reused for the next chunk, i.e. they must be consumed before the stream is advanced.</param>
+public record ReadChunk(
+    DateTime Begin,
+    DateTime End,
+    ReadRequest Request);
+
+/// <summary>
+/// Summary statistics of a buffer. <see cref="double.NaN"/> values are skipped.
+/// </summary>
+/// <param name="Min">The minimum value or <see cref="double.NaN"/> if there are no valid values.</param>
+/// <param name="Max">The maximum value or <see cref="double.NaN"/> if there are no valid values.</param>
+/// <param name="Mean">The mean value or <see cref="double.NaN"/> if there are no valid values.</param>
+/// <param name="ValidCount">The number of valid values.</param>
+/// <param name="NanCount">The number of <see cref="double.NaN"/> values.</param>
+public record SummaryStats(
+    double Min,
+    double Max,
+    double Mean,
+    int ValidCount,
+    int NanCount);
+
 internal class ReadRequestManager : IDisposable
 {
     private readonly IMemoryOwner<byte> _dataOwner;
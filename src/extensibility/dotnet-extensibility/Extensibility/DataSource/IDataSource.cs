@@ -3,6 +3,7 @@
 
 using Microsoft.Extensions.Logging;
 using Nexus.DataModel;
+using System.Runtime.CompilerServices;
 
 namespace Nexus.Extensibility;
 
@@ -33,6 +34,24 @@ public interface IDataSource : IExtension
         string path,
         CancellationToken cancellationToken);
 
+    /// <summary>
+    /// Streams the catalog registrations that are located under <paramref name="path"/>. By default, the result of <see cref="GetCatalogRegistrationsAsync"/> is yielded.
+    /// </summary>
+    /// <param name="path">The parent path for which to return catalog registrations.</param>
+    /// <param name="cancellationToken">A token to cancel the current operation.</param>
+    /// <returns>The catalog registrations stream.</returns>
+    async IAsyncEnumerable<CatalogRegistration> StreamCatalogRegistrationsAsync(
+        string path,
+        [EnumeratorCancellation] CancellationToken cancellationToken)
+    {
+        var catalogRegistrations = await GetCatalogRegistrationsAsync(path, cancellationToken);
+
+        foreach (var catalogRegistration in catalogRegistrations)
+        {
+            yield return catalogRegistration;
+        }
+    }
+
     /// <summary>
     /// Gets the requested <see cref="ResourceCatalog"/>.
     /// </summary>
@@ -67,6 +86,68 @@ public interface IDataSource : IExtension
         DateTime end,
         CancellationToken cancellationToken);
 
+    /// <summary>
+    /// Gets the point in time when the data of the <see cref="ResourceCatalog"/> has been modified last. By default, <see langword="null"/> is returned which means that it is unknown.
+    /// </summary>
+    /// <param name="catalogId">The catalog identifier.</param>
+    /// <param name="cancellationToken">A token to cancel the current operation.</param>
+    /// <returns>The last modified task.</returns>
+    Task<DateTime?> GetLastModifiedAsync(
+        string catalogId,
+        CancellationToken cancellationToken)
+    {
+        return Task.FromResult<DateTime?>(default);
+    }
+
+    /// <summary>
+    /// Streams the data of a single catalog item. By default, the period is split into chunks of at most <see cref="ReadStreamOptions.ChunkSize"/> elements and each chunk is read via <see cref="ReadAsync"/>. The cancellation token is checked before each chunk so that a cancelled stream stops promptly with an <see cref="OperationCanceledException"/>. The chunks are filled in <see cref="ReadStreamOptions.RequestedDataType"/> if set, whereby the data is read in the native data type of the representation and converted afterwards unless the data source is able to provide the requested data type itself (see <see cref="CanProvideDataType"/>). The chunks share a single pair of buffers, i.e. each chunk must be consumed before the stream is advanced.
+    /// </summary>
+    /// <param name="begin">The beginning of the period to read.</param>
+    /// <param name="end">The end of the period to read.</param>
+    /// <param name="catalogItem">The catalog item to read.</param>
+    /// <param name="readData">A delegate to asynchronously read data from Nexus.</param>
+    /// <param name="options">The optional stream options.</param>
+    /// <param name="cancellationToken">A token to cancel the current operation.</param>
+    /// <returns>The chunk stream.</returns>
+    IAsyncEnumerable<ReadChunk> ReadStreamAsync(
+        DateTime begin,
+        DateTime end,
+        CatalogItem catalogItem,
+        ReadDataHandler readData,
+        ReadStreamOptions? options,
+        CancellationToken cancellationToken)
+    {
+        return ExtensibilityUtilities.ReadStreamAsync(this, begin, end, catalogItem, readData, options, cancellationToken);
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if the data source is able to resample the specified resource itself. If so, Nexus requests aggregated representations (e.g. <c>1_min_mean</c>) directly instead of reading the original data and aggregating it itself. Directly read representations are cached like any other aggregated representation, i.e. only periods which are not yet cached are requested. By default, <see langword="false"/> is returned.
+    /// </summary>
+    /// <param name="catalogId">The catalog identifier.</param>
+    /// <param name="resourceId">The resource identifier.</param>
+    /// <returns>A boolean which indicates if resampling is supported.</returns>
+    bool CanResample(
+        string catalogId,
+        string resourceId)
+    {
+        return false;
+    }
+
+    /// <summary>
+    /// Gets a boolean which indicates if the data source is able to fill the data buffer of the specified resource in a data type other than the one of its representation. Only if so, Nexus sets <see cref="ReadRequest.RequestedDataType"/>, otherwise the data is read in the native data type and converted afterwards. By default, <see langword="false"/> is returned.
+    /// </summary>
+    /// <param name="catalogId">The catalog identifier.</param>
+    /// <param name="resourceId">The resource identifier.</param>
+    /// <param name="dataType">The requested data type.</param>
+    /// <returns>A boolean which indicates if the requested data type is supported.</returns>
+    bool CanProvideDataType(
+        string catalogId,
+        string resourceId,
+        NexusDataType dataType)
+    {
+        return false;
+    }
+
     /// <summary>
     /// Performs a number of read requests.
     /// </summary>
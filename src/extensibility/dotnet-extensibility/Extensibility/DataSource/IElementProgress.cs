@@ -0,0 +1,17 @@
+﻿// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+namespace Nexus.Extensibility;
+
+/// <summary>
+/// A progress receiver which is interested in the absolute number of processed elements, e.g. to compute throughput or the estimated time of arrival. Data sources report to it via <see cref="ExtensibilityUtilities.ReportElements"/>.
+/// </summary>
+public interface IElementProgress : IProgress<double>
+{
+    /// <summary>
+    /// Reports the number of processed elements.
+    /// </summary>
+    /// <param name="done">The number of elements processed so far.</param>
+    /// <param name="total">The total number of elements.</param>
+    void ReportElements(long done, long total);
+}
@@ -0,0 +1,25 @@
+﻿// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using System.Text.Json;
+
+namespace Nexus.Extensibility;
+
+/// <summary>
+/// A data source whose source configuration evolves over time. Use <see cref="ExtensibilityUtilities.UpgradeSourceConfiguration"/> to bring a stored configuration up to the current version.
+/// </summary>
+public interface IVersionedConfiguration
+{
+    /// <summary>
+    /// Gets the current version of the source configuration.
+    /// </summary>
+    int CurrentVersion { get; }
+
+    /// <summary>
+    /// Migrates the source configuration from the specified version to the next version.
+    /// </summary>
+    /// <param name="fromVersion">The version of the source configuration.</param>
+    /// <param name="configuration">The source configuration.</param>
+    /// <returns>The source configuration in the version <paramref name="fromVersion"/> + 1.</returns>
+    IReadOnlyDictionary<string, JsonElement> Migrate(int fromVersion, IReadOnlyDictionary<string, JsonElement> configuration);
+}
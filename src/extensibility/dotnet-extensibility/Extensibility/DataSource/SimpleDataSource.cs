@@ -3,6 +3,7 @@
 
 using Microsoft.Extensions.Logging;
 using Nexus.DataModel;
+using System.Runtime.CompilerServices;
 
 namespace Nexus.Extensibility;
 
@@ -38,6 +39,19 @@ public abstract class SimpleDataSource : IDataSource
         string path,
         CancellationToken cancellationToken);
 
+    /// <inheritdoc />
+    public virtual async IAsyncEnumerable<CatalogRegistration> StreamCatalogRegistrationsAsync(
+        string path,
+        [EnumeratorCancellation] CancellationToken cancellationToken)
+    {
+        var catalogRegistrations = await GetCatalogRegistrationsAsync(path, cancellationToken);
+
+        foreach (var catalogRegistration in catalogRegistrations)
+        {
+            yield return catalogRegistration;
+        }
+    }
+
     /// <inheritdoc />
     public abstract Task<ResourceCatalog> GetCatalogAsync(
         string catalogId,
@@ -61,6 +75,43 @@ public abstract class SimpleDataSource : IDataSource
         return Task.FromResult(double.NaN);
     }
 
+    /// <inheritdoc />
+    public virtual Task<DateTime?> GetLastModifiedAsync(
+        string catalogId,
+        CancellationToken cancellationToken)
+    {
+        return Task.FromResult<DateTime?>(default);
+    }
+
+    /// <inheritdoc />
+    public virtual bool CanResample(
+        string catalogId,
+        string resourceId)
+    {
+        return false;
+    }
+
+    /// <inheritdoc />
+    public virtual bool CanProvideDataType(
+        string catalogId,
+        string resourceId,
+        NexusDataType dataType)
+    {
+        return false;
+    }
+
+    /// <inheritdoc />
+    public virtual IAsyncEnumerable<ReadChunk> ReadStreamAsync(
+        DateTime begin,
+        DateTime end,
+        CatalogItem catalogItem,
+        ReadDataHandler readData,
+        ReadStreamOptions? options,
+        CancellationToken cancellationToken)
+    {
+        return ExtensibilityUtilities.ReadStreamAsync(this, begin, end, catalogItem, readData, options, cancellationToken);
+    }
+
     /// <inheritdoc />
     public abstract Task ReadAsync(
         DateTime begin,
@@ -0,0 +1,202 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using System.Globalization;
+
+namespace Nexus.Extensibility;
+
+/* Grammar:
+ *
+ * expression = term { ("+" | "-") term }
+ * term       = factor { ("*" | "/") factor }
+ * factor     = ["-"] ( number | identifier | "(" expression ")" )
+ */
+internal class ExpressionEvaluator(
+    string expression,
+    IReadOnlyDictionary<string, ReadOnlyMemory<double>>? inputs,
+    int length)
+{
+    private readonly string _expression = expression;
+    private readonly IReadOnlyDictionary<string, ReadOnlyMemory<double>>? _inputs = inputs;
+    private readonly int _length = length;
+    private int _position;
+
+    /* the distinct identifiers in order of their first occurrence */
+    public List<string> Identifiers { get; } = [];
+
+    public static List<string> GetIdentifiers(string expression)
+    {
+        /* without inputs, the expression is only parsed */
+        var evaluator = new ExpressionEvaluator(expression, inputs: default, length: 0);
+
+        evaluator.Evaluate();
+
+        return evaluator.Identifiers;
+    }
+
+    public double[] Evaluate()
+    {
+        _position = 0;
+
+        var result = ParseExpression();
+
+        SkipWhitespace();
+
+        if (_position != _expression.Length)
+            throw new ArgumentException($"Unexpected character '{_expression[_position]}' at position {_position} of the expression.");
+
+        return result;
+    }
+
+    private double[] ParseExpression()
+    {
+        var left = ParseTerm();
+
+        while (TryConsume('+', '-', out var @operator))
+        {
+            var right = ParseTerm();
+
+            for (int i = 0; i < _length; i++)
+            {
+                left[i] = @operator == '+'
+                    ? left[i] + right[i]
+                    : left[i] - right[i];
+            }
+        }
+
+        return left;
+    }
+
+    private double[] ParseTerm()
+    {
+        var left = ParseFactor();
+
+        while (TryConsume('*', '/', out var @operator))
+        {
+            var right = ParseFactor();
+
+            for (int i = 0; i < _length; i++)
+            {
+                if (@operator == '*')
+                    left[i] *= right[i];
+
+                else
+                    left[i] = right[i] == 0
+                        ? double.NaN
+                        : left[i] / right[i];
+            }
+        }
+
+        return left;
+    }
+
+    private double[] ParseFactor()
+    {
+        SkipWhitespace();
+
+        if (_position == _expression.Length)
+            throw new ArgumentException("Unexpected end of the expression.");
+
+        var current = _expression[_position];
+
+        /* negation */
+        if (current == '-')
+        {
+            _position++;
+
+            var operand = ParseFactor();
+
+            for (int i = 0; i < _length; i++)
+            {
+                operand[i] = -operand[i];
+            }
+
+            return operand;
+        }
+
+        /* parentheses */
+        if (current == '(')
+        {
+            _position++;
+
+            var result = ParseExpression();
+
+            if (!TryConsume(')', ')', out _))
+                throw new ArgumentException("The expression contains an unclosed parenthesis.");
+
+            return result;
+        }
+
+        /* number */
+        if (char.IsDigit(current) || current == '.')
+        {
+            var start = _position;
+
+            while (_position < _expression.Length && (char.IsDigit(_expression[_position]) || _expression[_position] == '.'))
+            {
+                _position++;
+            }
+
+            var numberString = _expression[start.._position];
+
+            if (!double.TryParse(numberString, NumberStyles.Float, CultureInfo.InvariantCulture, out var number))
+                throw new ArgumentException($"The number {numberString} is not valid.");
+
+            return Enumerable.Repeat(number, _length).ToArray();
+        }
+
+        /* identifier */
+        if (char.IsLetter(current) || current == '_')
+        {
+            var start = _position;
+
+            while (_position < _expression.Length && (char.IsLetterOrDigit(_expression[_position]) || _expression[_position] == '_'))
+            {
+                _position++;
+            }
+
+            var identifier = _expression[start.._position];
+
+            if (!Identifiers.Contains(identifier))
+                Identifiers.Add(identifier);
+
+            if (_inputs is null)
+                return new double[_length];
+
+            if (!_inputs.TryGetValue(identifier, out var input))
+                throw new ArgumentException($"The expression refers to the unknown input {identifier}.");
+
+            return input.ToArray();
+        }
+
+        throw new ArgumentException($"Unexpected character '{current}' at position {_position} of the expression.");
+    }
+
+    private bool TryConsume(char option1, char option2, out char consumed)
+    {
+        SkipWhitespace();
+
+        consumed = default;
+
+        if (_position == _expression.Length)
+            return false;
+
+        var current = _expression[_position];
+
+        if (current != option1 && current != option2)
+            return false;
+
+        consumed = current;
+        _position++;
+
+        return true;
+    }
+
+    private void SkipWhitespace()
+    {
+        while (_position < _expression.Length && char.IsWhiteSpace(_expression[_position]))
+        {
+            _position++;
+        }
+    }
+}
@@ -3,6 +3,11 @@
 
 using Nexus.DataModel;
 using System.Buffers;
+using System.Globalization;
+using System.Numerics;
+using System.Runtime.CompilerServices;
+using System.Text;
+using System.Text.Json;
 
 namespace Nexus.Extensibility;
 
@@ -11,6 +16,11 @@ namespace Nexus.Extensibility;
 /// </summary>
 public static class ExtensibilityUtilities
 {
+    /// <summary>
+    /// A constant with the key of the source configuration version. A configuration without this key is treated as version 1.
+    /// </summary>
+    public const string ConfigurationVersionKey = "configuration-version";
+
     /// <summary>
     /// Creates buffers of the correct size for a given representation and time period.
     /// </summary>
@@ -20,19 +30,837 @@ public static class ExtensibilityUtilities
     /// <returns>The data and status buffers.</returns>
     public static (Memory<byte>, Memory<byte>) CreateBuffers(Representation representation, DateTime begin, DateTime end)
     {
-        var elementCount = CalculateElementCount(begin, end, representation.SamplePeriod);
+        var (dataLength, statusLength) = GetBufferSizes(representation, begin, end);
 
-        var dataOwner = MemoryPool<byte>.Shared.Rent(elementCount * representation.ElementSize);
-        var data = dataOwner.Memory[..(elementCount * representation.ElementSize)];
+        var dataOwner = MemoryPool<byte>.Shared.Rent(dataLength);
+        var data = dataOwner.Memory[..dataLength];
         data.Span.Clear();
 
-        var statusOwner = MemoryPool<byte>.Shared.Rent(elementCount);
-        var status = statusOwner.Memory[..elementCount];
+        var statusOwner = MemoryPool<byte>.Shared.Rent(statusLength);
+        var status = statusOwner.Memory[..statusLength];
         status.Span.Clear();
 
         return (data, status);
     }
 
+    /// <summary>
+    /// Gets the sizes of the data and status buffers for a given representation and time period without allocating them. This allows a data source to validate the size of its output in advance.
+    /// </summary>
+    /// <param name="representation">The representation.</param>
+    /// <param name="begin">The beginning of the time period.</param>
+    /// <param name="end">The end of the time period.</param>
+    /// <returns>The sizes of the data and status buffers in bytes.</returns>
+    /// <exception cref="ArgumentException">Thrown when the time period is negative or when the buffers would be too large.</exception>
+    public static (int DataLength, int StatusLength) GetBufferSizes(Representation representation, DateTime begin, DateTime end)
+    {
+        if (end < begin)
+            throw new ArgumentException("The end of the time period must not be before its beginning.");
+
+        var elementCount = (end.Ticks - begin.Ticks) / representation.SamplePeriod.Ticks;
+        var dataLength = elementCount * representation.ElementSize;
+
+        if (dataLength > int.MaxValue)
+            throw new ArgumentException($"The data buffer size of {dataLength} bytes exceeds the maximum buffer size.");
+
+        return ((int)dataLength, (int)elementCount);
+    }
+
+    /// <summary>
+    /// Marks all samples outside of the range [<paramref name="min"/>, <paramref name="max"/>] as invalid. This method mutates both buffers in place: the status of such a sample is set to 0x00 and its value to <see cref="double.NaN"/>.
+    /// </summary>
+    /// <param name="values">The values to check.</param>
+    /// <param name="status">The status buffer which belongs to <paramref name="values"/>.</param>
+    /// <param name="min">The minimum valid value.</param>
+    /// <param name="max">The maximum valid value.</param>
+    /// <exception cref="ArgumentException">Thrown when the buffers differ in length.</exception>
+    public static void FlagOutOfRange(Span<double> values, Span<byte> status, double min, double max)
+    {
+        if (values.Length != status.Length)
+            throw new ArgumentException("The data and status buffers must be of equal length.");
+
+        for (int i = 0; i < values.Length; i++)
+        {
+            var value = values[i];
+
+            if (value < min || value > max)
+            {
+                values[i] = double.NaN;
+                status[i] = 0;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Derives gaps from per-day availability values. Consecutive days with an availability below <paramref name="threshold"/> are coalesced into a single gap.
+    /// </summary>
+    /// <param name="begin">The beginning of the first day.</param>
+    /// <param name="dayValues">The availability values, one per day.</param>
+    /// <param name="threshold">The availability below which a day is considered a gap.</param>
+    /// <returns>The list of gaps.</returns>
+    public static IReadOnlyList<(DateTime Begin, DateTime End)> GetGapsFromAvailability(DateTime begin, ReadOnlySpan<double> dayValues, double threshold)
+    {
+        var gaps = new List<(DateTime Begin, DateTime End)>();
+        var gapStart = -1;
+
+        for (int i = 0; i <= dayValues.Length; i++)
+        {
+            var isGap = i < dayValues.Length && dayValues[i] < threshold;
+
+            if (isGap && gapStart == -1)
+            {
+                gapStart = i;
+            }
+
+            else if (!isGap && gapStart != -1)
+            {
+                gaps.Add((begin.AddDays(gapStart), begin.AddDays(i)));
+                gapStart = -1;
+            }
+        }
+
+        return gaps;
+    }
+
+    /// <summary>
+    /// Computes the common availability of multiple resources, e.g. to find the days where all resources plotted together have data. A day is considered available (1.0) only if the availability of every resource is greater than or equal to <paramref name="threshold"/>, otherwise it is considered unavailable (0.0).
+    /// </summary>
+    /// <param name="perResourceDayValues">The availability values of each resource, one per day.</param>
+    /// <param name="threshold">The availability below which a day is considered unavailable.</param>
+    /// <returns>The common availability values, one per day.</returns>
+    public static double[] GetCommonAvailability(IReadOnlyList<ReadOnlyMemory<double>> perResourceDayValues, double threshold)
+    {
+        if (perResourceDayValues.Count == 0)
+            return [];
+
+        var length = perResourceDayValues[0].Length;
+
+        if (perResourceDayValues.Any(dayValues => dayValues.Length != length))
+            throw new ArgumentException("The availability values of all resources must be of equal length.");
+
+        var result = new double[length];
+
+        for (int i = 0; i < length; i++)
+        {
+            result[i] = perResourceDayValues.All(dayValues => dayValues.Span[i] >= threshold)
+                ? 1.0
+                : 0.0;
+        }
+
+        return result;
+    }
+
+    /// <summary>
+    /// Computes the availability from a status buffer, i.e. the fraction of valid status bytes (<c>0x01</c>) per bucket. This allows data sources with cached data to implement <see cref="IDataSource.GetAvailabilityAsync"/> without a separate scan. A trailing partial bucket is computed from the remaining elements.
+    /// </summary>
+    /// <param name="status">The status buffer.</param>
+    /// <param name="elementsPerBucket">The number of status bytes per bucket.</param>
+    /// <returns>The availability of each bucket within the range [0, 1].</returns>
+    public static double[] GetAvailabilityFromStatus(ReadOnlySpan<byte> status, int elementsPerBucket)
+    {
+        if (elementsPerBucket <= 0)
+            throw new ArgumentException("The number of elements per bucket must be greater than zero.");
+
+        var bucketCount = (status.Length + elementsPerBucket - 1) / elementsPerBucket;
+        var result = new double[bucketCount];
+
+        for (int i = 0; i < bucketCount; i++)
+        {
+            var bucket = status.Slice(i * elementsPerBucket, Math.Min(elementsPerBucket, status.Length - i * elementsPerBucket));
+            result[i] = bucket.Count((byte)1) / (double)bucket.Length;
+        }
+
+        return result;
+    }
+
+    /// <summary>
+    /// Derives the binary status from a quality buffer. A sample is considered valid (0x01) if its quality is greater than or equal to <paramref name="threshold"/>, otherwise it is considered invalid (0x00).
+    /// </summary>
+    /// <param name="quality">The quality buffer.</param>
+    /// <param name="status">The status buffer to fill.</param>
+    /// <param name="threshold">The minimum quality of a valid sample.</param>
+    public static void DeriveStatusFromQuality(ReadOnlySpan<byte> quality, Span<byte> status, byte threshold)
+    {
+        if (quality.Length != status.Length)
+            throw new ArgumentException("The quality and status buffers must be of equal length.");
+
+        for (int i = 0; i < quality.Length; i++)
+        {
+            status[i] = quality[i] >= threshold
+                ? (byte)1
+                : (byte)0;
+        }
+    }
+
+    /// <summary>
+    /// Merges two status buffers of the same resource, e.g. from a primary and a backup source.
+    /// </summary>
+    /// <param name="status1">The first status buffer.</param>
+    /// <param name="status2">The second status buffer.</param>
+    /// <param name="mode">The merge mode.</param>
+    /// <returns>The merged status buffer.</returns>
+    public static byte[] MergeStatus(ReadOnlySpan<byte> status1, ReadOnlySpan<byte> status2, StatusMergeMode mode)
+    {
+        if (status1.Length != status2.Length)
+            throw new ArgumentException("The status buffers must be of equal length.");
+
+        var result = new byte[status1.Length];
+
+        for (int i = 0; i < result.Length; i++)
+        {
+            var isValid = mode switch
+            {
+                StatusMergeMode.And => status1[i] == 1 && status2[i] == 1,
+                StatusMergeMode.Or => status1[i] == 1 || status2[i] == 1,
+                _ => throw new ArgumentException($"The merge mode {mode} is not supported.")
+            };
+
+            result[i] = isValid ? (byte)1 : (byte)0;
+        }
+
+        return result;
+    }
+
+    /// <summary>
+    /// Fills invalid samples of a primary buffer with valid samples of a backup buffer of the same resource. This method mutates both primary buffers in place: a patched sample takes the value of the backup sample and its status is set to 0x01.
+    /// </summary>
+    /// <param name="primary">The primary values.</param>
+    /// <param name="primaryStatus">The status buffer which belongs to <paramref name="primary"/>.</param>
+    /// <param name="backup">The backup values.</param>
+    /// <param name="backupStatus">The status buffer which belongs to <paramref name="backup"/>.</param>
+    public static void MergeData(Span<double> primary, Span<byte> primaryStatus, ReadOnlySpan<double> backup, ReadOnlySpan<byte> backupStatus)
+    {
+        if (primary.Length != primaryStatus.Length || primary.Length != backup.Length || primary.Length != backupStatus.Length)
+            throw new ArgumentException("The data and status buffers must be of equal length.");
+
+        for (int i = 0; i < primary.Length; i++)
+        {
+            if (primaryStatus[i] != 1 && backupStatus[i] == 1)
+            {
+                primary[i] = backup[i];
+                primaryStatus[i] = 1;
+            }
+        }
+    }
+
+    /// <summary>
+    /// Reports the read progress as the number of processed elements. If <paramref name="progress"/> is an <see cref="IElementProgress"/>, the absolute counts are forwarded, otherwise the fraction <paramref name="done"/> / <paramref name="total"/> is reported.
+    /// </summary>
+    /// <param name="progress">The progress to report to.</param>
+    /// <param name="done">The number of elements processed so far.</param>
+    /// <param name="total">The total number of elements.</param>
+    public static void ReportElements(IProgress<double> progress, long done, long total)
+    {
+        if (total <= 0)
+            throw new ArgumentException("The total number of elements must be greater than zero.");
+
+        if (done < 0 || done > total)
+            throw new ArgumentException("The number of processed elements must be between zero and the total number of elements.");
+
+        if (progress is IElementProgress elementProgress)
+            elementProgress.ReportElements(done, total);
+
+        else
+            progress.Report(done / (double)total);
+    }
+
+    /// <summary>
+    /// Creates read requests whose data and status buffers are slices of caller-provided buffers. This allows a single scratch buffer to be reused across reads instead of allocating new buffers per request.
+    /// </summary>
+    /// <param name="catalogItems">The catalog items to read.</param>
+    /// <param name="begin">The beginning of the time period.</param>
+    /// <param name="end">The end of the time period.</param>
+    /// <param name="data">The buffer to slice the data buffers from.</param>
+    /// <param name="status">The buffer to slice the status buffers from.</param>
+    /// <returns>The read requests.</returns>
+    /// <exception cref="ArgumentException">Thrown when the provided buffers are too small.</exception>
+    public static ReadRequest[] CreateReadRequests(
+        IReadOnlyList<CatalogItem> catalogItems,
+        DateTime begin,
+        DateTime end,
+        Memory<byte> data,
+        Memory<byte> status)
+    {
+        var requests = new ReadRequest[catalogItems.Count];
+        var dataOffset = 0;
+        var statusOffset = 0;
+
+        for (int i = 0; i < catalogItems.Count; i++)
+        {
+            var catalogItem = catalogItems[i];
+            var elementCount = CalculateElementCount(begin, end, catalogItem.Representation.SamplePeriod);
+            var byteCount = elementCount * catalogItem.Representation.ElementSize;
+
+            if (data.Length < dataOffset + byteCount || status.Length < statusOffset + elementCount)
+                throw new ArgumentException("The provided buffers are too small to hold the data of all catalog items.");
+
+            var dataSlice = data.Slice(dataOffset, byteCount);
+            var statusSlice = status.Slice(statusOffset, elementCount);
+
+            dataSlice.Span.Clear();
+            statusSlice.Span.Clear();
+
+            requests[i] = new ReadRequest(catalogItem, dataSlice, statusSlice);
+
+            dataOffset += byteCount;
+            statusOffset += elementCount;
+        }
+
+        return requests;
+    }
+
+    /// <summary>
+    /// Generates the timestamps of a series as Unix nanoseconds using integer addition only, which is much faster than per-sample <see cref="DateTime"/> arithmetic.
+    /// </summary>
+    /// <param name="begin">The timestamp of the first sample.</param>
+    /// <param name="samplePeriod">The sample period.</param>
+    /// <param name="count">The number of timestamps to generate.</param>
+    /// <returns>The timestamps as nanoseconds since the Unix epoch.</returns>
+    /// <exception cref="OverflowException">Thrown when a timestamp cannot be represented as 64-bit Unix nanoseconds, i.e. when it is before 1677-09-21 or after 2262-04-11.</exception>
+    public static IEnumerable<long> GetTimestampsNs(DateTime begin, TimeSpan samplePeriod, int count)
+    {
+        /* check the whole range up front so that the iterator itself cannot overflow */
+        var first = checked((begin.Ticks - DateTime.UnixEpoch.Ticks) * DataModelExtensions.NS_PER_TICK);
+        var step = checked(samplePeriod.Ticks * DataModelExtensions.NS_PER_TICK);
+        _ = checked(first + step * Math.Max(count - 1, 0));
+
+        return GetTimestampsNsCore(first, step, count);
+    }
+
+    private static IEnumerable<long> GetTimestampsNsCore(long first, long step, int count)
+    {
+        var current = first;
+
+        for (int i = 0; i < count; i++)
+        {
+            yield return current;
+            current += step;
+        }
+    }
+
+    /// <summary>
+    /// Estimates the memory consumption of a batch of read requests, i.e. the sum of the lengths of all data and status buffers. The sum is computed as 64-bit integer so that batches larger than 2 GB do not overflow. The addition is checked nevertheless.
+    /// </summary>
+    /// <param name="requests">The read requests.</param>
+    /// <returns>The number of bytes.</returns>
+    /// <exception cref="OverflowException">Thrown when the sum exceeds <see cref="long.MaxValue"/>.</exception>
+    public static long EstimateBatchBytes(IEnumerable<ReadRequest> requests)
+    {
+        var byteCount = 0L;
+
+        foreach (var request in requests)
+        {
+            byteCount = checked(byteCount + request.Data.Length + request.Status.Length);
+        }
+
+        return byteCount;
+    }
+
+    /// <summary>
+    /// Gets the distinct sample periods of a batch of read requests.
+    /// </summary>
+    /// <param name="requests">The read requests.</param>
+    /// <returns>The ordered set of sample periods.</returns>
+    public static SortedSet<TimeSpan> GetBatchPeriods(IEnumerable<ReadRequest> requests)
+    {
+        return new SortedSet<TimeSpan>(requests.Select(request => request.CatalogItem.Representation.SamplePeriod));
+    }
+
+    /// <summary>
+    /// Partitions a batch of read requests by the identifier of the catalog they belong to, e.g. to route them to the data sources which own the catalogs. The order of the requests within each partition is preserved.
+    /// </summary>
+    /// <param name="requests">The read requests.</param>
+    /// <returns>The read requests per catalog identifier.</returns>
+    public static IReadOnlyDictionary<string, ReadRequest[]> PartitionRequestsByCatalog(IEnumerable<ReadRequest> requests)
+    {
+        return requests
+            .GroupBy(request => request.CatalogItem.Catalog.Id)
+            .ToDictionary(group => group.Key, group => group.ToArray());
+    }
+
+    /// <summary>
+    /// Checks if all read requests of a batch share the same sample period.
+    /// </summary>
+    /// <param name="requests">The read requests.</param>
+    /// <returns>True if the batch has at most a single sample period, otherwise false.</returns>
+    public static bool BatchIsUniformPeriod(IEnumerable<ReadRequest> requests)
+    {
+        return GetBatchPeriods(requests).Count <= 1;
+    }
+
+    /// <summary>
+    /// Writes natively typed values into the data buffer of a read request. The values are converted into <see cref="ReadRequest.DataType"/> via <see cref="DataModelExtensions.EncodeDouble"/>, i.e. they follow the same clamping rules as all other encoding helpers.
+    /// </summary>
+    /// <typeparam name="T">The native type of the values.</typeparam>
+    /// <param name="request">The read request.</param>
+    /// <param name="values">The values to write.</param>
+    /// <exception cref="ArgumentException">Thrown when the number of values does not match the size of the data buffer.</exception>
+    public static void WriteData<T>(ReadRequest request, ReadOnlySpan<T> values) where T : unmanaged, INumber<T>
+    {
+        if (values.Length != request.ElementCount)
+            throw new ArgumentException("The number of values does not match the size of the data buffer.");
+
+        var dataType = request.DataType;
+        var elementSize = dataType.GetElementSize();
+        var target = request.Data.Span;
+
+        var byteOrder = BitConverter.IsLittleEndian
+            ? ByteOrder.LittleEndian
+            : ByteOrder.BigEndian;
+
+        for (int i = 0; i < values.Length; i++)
+        {
+            dataType.EncodeDouble(double.CreateSaturating(values[i]), target[(i * elementSize)..], byteOrder);
+        }
+    }
+
+    /// <summary>
+    /// Encodes values into an externally owned buffer without intermediate allocations. The values are converted into <paramref name="dataType"/> and saturated if the target type is narrower.
+    /// </summary>
+    /// <param name="destination">The buffer to write the encoded values into.</param>
+    /// <param name="values">The values to encode.</param>
+    /// <param name="dataType">The data type to encode the values as.</param>
+    /// <param name="allowInfinity">A boolean which indicates if infinite values are allowed.</param>
+    /// <returns>The number of bytes written.</returns>
+    /// <exception cref="ArgumentException">Thrown when the destination buffer is too small or when a value is infinite but infinite values are not allowed.</exception>
+    public static int EncodeInto(Span<byte> destination, ReadOnlySpan<double> values, NexusDataType dataType, bool allowInfinity = true)
+    {
+        var elementSize = dataType.GetElementSize();
+        var byteCount = values.Length * elementSize;
+
+        if (destination.Length < byteCount)
+            throw new ArgumentException($"The destination buffer is too small ({destination.Length} bytes) to hold {byteCount} bytes.");
+
+        var byteOrder = BitConverter.IsLittleEndian
+            ? ByteOrder.LittleEndian
+            : ByteOrder.BigEndian;
+
+        for (int i = 0; i < values.Length; i++)
+        {
+            dataType.EncodeDouble(values[i], destination[(i * elementSize)..], byteOrder, allowInfinity);
+        }
+
+        return byteCount;
+    }
+
+    /// <summary>
+    /// Converts a whole buffer from one data type into another. Each element is decoded via <paramref name="sourceDataType"/> and re-encoded via <paramref name="targetDataType"/>, whereby values are clamped if the target type is narrower (see <see cref="DataModelExtensions.EncodeDouble"/>).
+    /// </summary>
+    /// <param name="source">The buffer to convert.</param>
+    /// <param name="sourceDataType">The data type of the source buffer.</param>
+    /// <param name="targetDataType">The data type of the returned buffer.</param>
+    /// <param name="byteOrder">The byte order of both buffers.</param>
+    /// <returns>The converted buffer.</returns>
+    /// <exception cref="ArgumentException">Thrown when the length of the source buffer is not a multiple of the source element size.</exception>
+    public static byte[] ConvertBuffer(ReadOnlySpan<byte> source, NexusDataType sourceDataType, NexusDataType targetDataType, ByteOrder byteOrder = ByteOrder.LittleEndian)
+    {
+        var sourceElementSize = sourceDataType.GetElementSize();
+        var targetElementSize = targetDataType.GetElementSize();
+
+        if (source.Length % sourceElementSize != 0)
+            throw new ArgumentException($"The length of the source buffer ({source.Length} bytes) is not a multiple of the element size of {sourceDataType}.");
+
+        var elementCount = source.Length / sourceElementSize;
+        var target = new byte[elementCount * targetElementSize];
+
+        ConvertInto(target, source, sourceDataType, targetDataType, byteOrder);
+
+        return target;
+    }
+
+    /// <summary>
+    /// Converts a whole buffer from one data type into an externally owned buffer without intermediate allocations. The conversion follows the same rules as <see cref="ConvertBuffer"/>.
+    /// </summary>
+    /// <param name="destination">The buffer to write the converted values into.</param>
+    /// <param name="source">The buffer to convert.</param>
+    /// <param name="sourceDataType">The data type of the source buffer.</param>
+    /// <param name="targetDataType">The data type of the destination buffer.</param>
+    /// <param name="byteOrder">The byte order of both buffers.</param>
+    /// <returns>The number of bytes written.</returns>
+    /// <exception cref="ArgumentException">Thrown when the length of the source buffer is not a multiple of the source element size or when the destination buffer is too small.</exception>
+    public static int ConvertInto(Span<byte> destination, ReadOnlySpan<byte> source, NexusDataType sourceDataType, NexusDataType targetDataType, ByteOrder byteOrder = ByteOrder.LittleEndian)
+    {
+        var sourceElementSize = sourceDataType.GetElementSize();
+        var targetElementSize = targetDataType.GetElementSize();
+
+        if (source.Length % sourceElementSize != 0)
+            throw new ArgumentException($"The length of the source buffer ({source.Length} bytes) is not a multiple of the element size of {sourceDataType}.");
+
+        var elementCount = source.Length / sourceElementSize;
+        var byteCount = elementCount * targetElementSize;
+
+        if (destination.Length < byteCount)
+            throw new ArgumentException($"The destination buffer is too small ({destination.Length} bytes) to hold {byteCount} bytes.");
+
+        for (int i = 0; i < elementCount; i++)
+        {
+            var value = sourceDataType.DecodeDouble(source[(i * sourceElementSize)..], byteOrder);
+            targetDataType.EncodeDouble(value, destination[(i * targetElementSize)..], byteOrder);
+        }
+
+        return byteCount;
+    }
+
+    /// <summary>
+    /// Computes the minimum, maximum, mean and the number of valid and <see cref="double.NaN"/> values in a single pass.
+    /// </summary>
+    /// <param name="values">The values.</param>
+    /// <returns>The summary statistics.</returns>
+    public static SummaryStats GetSummaryStats(ReadOnlySpan<double> values)
+    {
+        var min = double.PositiveInfinity;
+        var max = double.NegativeInfinity;
+        var sum = 0.0;
+        var validCount = 0;
+        var nanCount = 0;
+
+        foreach (var value in values)
+        {
+            if (double.IsNaN(value))
+            {
+                nanCount++;
+                continue;
+            }
+
+            min = Math.Min(min, value);
+            max = Math.Max(max, value);
+            sum += value;
+            validCount++;
+        }
+
+        if (validCount == 0)
+            return new SummaryStats(double.NaN, double.NaN, double.NaN, validCount, nanCount);
+
+        return new SummaryStats(min, max, sum / validCount, validCount, nanCount);
+    }
+
+    /// <summary>
+    /// Removes the wraps of a counter which restarts at zero when it reaches <paramref name="max"/> so that a monotonic series is produced. <see cref="double.NaN"/> values are preserved.
+    /// </summary>
+    /// <param name="values">The raw counter values.</param>
+    /// <param name="max">The value at which the counter wraps to zero.</param>
+    /// <returns>The unwrapped counter values.</returns>
+    public static double[] UnwrapCounter(ReadOnlySpan<double> values, double max)
+    {
+        var result = new double[values.Length];
+        var offset = 0.0;
+        var previous = double.NaN;
+
+        for (int i = 0; i < values.Length; i++)
+        {
+            var current = values[i];
+
+            if (double.IsNaN(current))
+            {
+                result[i] = double.NaN;
+                continue;
+            }
+
+            if (current < previous)
+                offset += max;
+
+            result[i] = current + offset;
+            previous = current;
+        }
+
+        return result;
+    }
+
+    /// <summary>
+    /// Writes the data of a read request as JSON lines, i.e. one object of the form <c>{"t":"2020-01-01T00:00:00Z","v":1.5}</c> per sample. Timestamps are formatted according to ISO 8601 and invalid or non-finite values are written as <c>null</c>.
+    /// </summary>
+    /// <param name="stream">The stream to write to.</param>
+    /// <param name="begin">The timestamp of the first sample.</param>
+    /// <param name="request">The read request which contains the data and status buffers.</param>
+    public static void WriteJsonLines(Stream stream, DateTime begin, ReadRequest request)
+    {
+        var samplePeriod = request.CatalogItem.Representation.SamplePeriod;
+        var elementSize = request.DataType.GetElementSize();
+        var data = request.Data.Span;
+        var status = request.Status.Span;
+
+        var byteOrder = BitConverter.IsLittleEndian
+            ? ByteOrder.LittleEndian
+            : ByteOrder.BigEndian;
+
+        using var writer = new Utf8JsonWriter(stream);
+
+        for (int i = 0; i < request.ElementCount; i++)
+        {
+            var value = request.DataType.DecodeDouble(data.Slice(i * elementSize, elementSize), byteOrder);
+
+            writer.WriteStartObject();
+            writer.WriteString("t", begin + i * samplePeriod);
+
+            if (status[i] == 1 && double.IsFinite(value))
+                writer.WriteNumber("v", value);
+
+            else
+                writer.WriteNull("v");
+
+            writer.WriteEndObject();
+            writer.Flush();
+            writer.Reset();
+
+            stream.WriteByte((byte)'\n');
+        }
+    }
+
+    /// <summary>
+    /// Exports the data of a catalog item as CSV by reading it chunk by chunk (see <see cref="IDataSource.ReadStreamAsync"/>) and writing the rows incrementally so that the whole period is never held in memory. The first column contains ISO 8601 timestamps and the second column the values. Invalid or non-finite values are written as empty fields (see <see cref="WriteJsonLines"/>).
+    /// </summary>
+    /// <param name="writer">The writer to write the CSV rows to.</param>
+    /// <param name="dataSource">The data source to read from.</param>
+    /// <param name="catalogItem">The catalog item to export.</param>
+    /// <param name="begin">The beginning of the period to export.</param>
+    /// <param name="end">The end of the period to export.</param>
+    /// <param name="readData">A delegate to asynchronously read data from Nexus.</param>
+    /// <param name="chunkSize">The maximum number of elements per chunk.</param>
+    /// <param name="cancellationToken">A token to cancel the current operation.</param>
+    /// <returns>The task.</returns>
+    public static async Task WriteCsvAsync(
+        TextWriter writer,
+        IDataSource dataSource,
+        CatalogItem catalogItem,
+        DateTime begin,
+        DateTime end,
+        ReadDataHandler readData,
+        int chunkSize,
+        CancellationToken cancellationToken)
+    {
+        var samplePeriod = catalogItem.Representation.SamplePeriod;
+        var options = new ReadStreamOptions() { ChunkSize = chunkSize };
+
+        var byteOrder = BitConverter.IsLittleEndian
+            ? ByteOrder.LittleEndian
+            : ByteOrder.BigEndian;
+
+        await writer.WriteLineAsync($"timestamp,{catalogItem.Resource.Id}");
+
+        await foreach (var chunk in dataSource.ReadStreamAsync(begin, end, catalogItem, readData, options, cancellationToken))
+        {
+            var request = chunk.Request;
+            var elementSize = request.DataType.GetElementSize();
+
+            for (int i = 0; i < request.ElementCount; i++)
+            {
+                var timestamp = (chunk.Begin + i * samplePeriod).ToString("o", CultureInfo.InvariantCulture);
+
+                var value = request.DataType.DecodeDouble(request.Data.Span.Slice(i * elementSize, elementSize), byteOrder);
+
+                /* same policy as WriteJsonLines: an empty field is the counterpart of null */
+                var field = request.Status.Span[i] == 1 && double.IsFinite(value)
+                    ? value.ToString(CultureInfo.InvariantCulture)
+                    : string.Empty;
+
+                await writer.WriteLineAsync($"{timestamp},{field}");
+            }
+        }
+
+        await writer.FlushAsync(cancellationToken);
+    }
+
+    /// <summary>
+    /// Evaluates an arithmetic expression element-wise over a set of equally sized inputs. Supported are the operators <c>+</c>, <c>-</c>, <c>*</c> and <c>/</c>, parentheses, numeric literals and identifiers which refer to the provided inputs. A division by zero results in <see cref="double.NaN"/>.
+    /// </summary>
+    /// <param name="expression">The expression to evaluate, e.g. <c>voltage * current</c>.</param>
+    /// <param name="inputs">The inputs referenced by the expression.</param>
+    /// <returns>The evaluated values.</returns>
+    /// <exception cref="ArgumentException">Thrown when the expression is invalid, refers to an unknown input, when there are no inputs or when the inputs differ in length.</exception>
+    public static double[] EvaluateExpression(string expression, IReadOnlyDictionary<string, ReadOnlyMemory<double>> inputs)
+    {
+        /* the inputs determine the number of elements */
+        if (inputs.Count == 0)
+            throw new ArgumentException("The expression requires at least one input.");
+
+        var lengths = inputs.Values
+            .Select(input => input.Length)
+            .Distinct()
+            .ToList();
+
+        if (lengths.Count > 1)
+            throw new ArgumentException("The inputs of the expression must be of equal length.");
+
+        var evaluator = new ExpressionEvaluator(expression, inputs, lengths[0]);
+
+        return evaluator.Evaluate();
+    }
+
+    /// <summary>
+    /// Gets the union of the resource identifiers of two catalogs, e.g. as a cheap preflight before merging them.
+    /// </summary>
+    /// <param name="catalog1">The first catalog.</param>
+    /// <param name="catalog2">The second catalog.</param>
+    /// <returns>The ordered set of resource identifiers.</returns>
+    public static SortedSet<string> GetResourceIdUnion(ResourceCatalog catalog1, ResourceCatalog catalog2)
+    {
+        var resourceIds = (catalog1.Resources ?? [])
+            .Concat(catalog2.Resources ?? [])
+            .Select(resource => resource.Id);
+
+        return new SortedSet<string>(resourceIds, StringComparer.Ordinal);
+    }
+
+    /// <summary>
+    /// Upgrades a stored source configuration to the current version by applying all migrations sequentially.
+    /// </summary>
+    /// <param name="versionedConfiguration">The data source which provides the migrations.</param>
+    /// <param name="configuration">The stored source configuration.</param>
+    /// <returns>The upgraded source configuration, tagged with the current version.</returns>
+    /// <exception cref="Exception">Thrown when the stored version is not a positive integer or when it is newer than the current version.</exception>
+    public static IReadOnlyDictionary<string, JsonElement> UpgradeSourceConfiguration(
+        IVersionedConfiguration versionedConfiguration,
+        IReadOnlyDictionary<string, JsonElement>? configuration)
+    {
+        configuration ??= new Dictionary<string, JsonElement>();
+
+        var version = 1;
+
+        if (configuration.TryGetValue(ConfigurationVersionKey, out var versionElement))
+        {
+            if (versionElement.ValueKind != JsonValueKind.Number || !versionElement.TryGetInt32(out version) || version <= 0)
+                throw new Exception($"The source configuration version {versionElement.GetRawText()} is not a positive integer.");
+        }
+
+        var currentVersion = versionedConfiguration.CurrentVersion;
+
+        if (version > currentVersion)
+            throw new Exception($"The source configuration version {version} is newer than the current version {currentVersion}.");
+
+        for (; version < currentVersion; version++)
+        {
+            configuration = versionedConfiguration.Migrate(version, configuration);
+        }
+
+        var upgradedConfiguration = configuration.ToDictionary(entry => entry.Key, entry => entry.Value);
+        upgradedConfiguration[ConfigurationVersionKey] = JsonSerializer.SerializeToElement(currentVersion);
+
+        return upgradedConfiguration;
+    }
+
+    /// <summary>
+    /// Renders a catalog as an indented, human-readable tree of its resources and representations. This is meant as a diagnostic aid and not as a serialization format.
+    /// </summary>
+    /// <param name="catalog">The catalog to render.</param>
+    /// <returns>The rendered tree.</returns>
+    public static string RenderCatalogTree(ResourceCatalog catalog)
+    {
+        var builder = new StringBuilder();
+
+        builder.AppendLine(catalog.Id);
+
+        foreach (var resource in catalog.Resources ?? [])
+        {
+            var unit = resource.Properties?.GetStringValue(DataModelExtensions.UnitKey);
+
+            builder.AppendLine(unit is null
+                ? $"  {resource.Id}"
+                : $"  {resource.Id} ({unit})");
+
+            foreach (var representation in resource.Representations ?? [])
+            {
+                builder.AppendLine($"    {representation.Id} ({representation.DataType})");
+            }
+        }
+
+        return builder.ToString();
+    }
+
+    internal static async IAsyncEnumerable<ReadChunk> ReadStreamAsync(
+        IDataSource dataSource,
+        DateTime begin,
+        DateTime end,
+        CatalogItem catalogItem,
+        ReadDataHandler readData,
+        ReadStreamOptions? options,
+        [EnumeratorCancellation] CancellationToken cancellationToken)
+    {
+        options ??= new ReadStreamOptions();
+
+        var samplePeriod = catalogItem.Representation.SamplePeriod;
+        var chunkPeriod = samplePeriod * options.ChunkSize;
+        var currentBegin = begin;
+
+        /* the source reads in the native data type unless it has declared support for the requested one */
+        var nativeDataType = catalogItem.Representation.DataType;
+        var targetDataType = options.RequestedDataType ?? nativeDataType;
+
+        var readDataType = dataSource.CanProvideDataType(catalogItem.Catalog.Id, catalogItem.Resource.Id, targetDataType)
+            ? targetDataType
+            : nativeDataType;
+
+        var requestedDataType = readDataType == nativeDataType
+            ? default(NexusDataType?)
+            : readDataType;
+
+        var byteOrder = BitConverter.IsLittleEndian
+            ? ByteOrder.LittleEndian
+            : ByteOrder.BigEndian;
+
+        /* the buffers are allocated once and reused for all chunks */
+        var readElementSize = readDataType.GetElementSize();
+        var targetElementSize = targetDataType.GetElementSize();
+        var maxElementCount = Math.Min(options.ChunkSize, CalculateElementCount(begin, end, samplePeriod));
+        var data = new byte[maxElementCount * readElementSize];
+        var status = new byte[maxElementCount];
+
+        var targetData = readDataType == targetDataType
+            ? data
+            : new byte[maxElementCount * targetElementSize];
+
+        while (currentBegin < end)
+        {
+            cancellationToken.ThrowIfCancellationRequested();
+
+            var currentEnd = end - currentBegin > chunkPeriod
+                ? currentBegin + chunkPeriod
+                : end;
+
+            var elementCount = CalculateElementCount(currentBegin, currentEnd, samplePeriod);
+
+            Array.Clear(data);
+            Array.Clear(status);
+
+            var request = new ReadRequest(
+                catalogItem,
+                data.AsMemory(0, elementCount * readElementSize),
+                status.AsMemory(0, elementCount))
+            {
+                RequestedDataType = requestedDataType
+            };
+
+            await dataSource.ReadAsync(
+                currentBegin,
+                currentEnd,
+                [request],
+                readData,
+                new Progress<double>(),
+                cancellationToken);
+
+            /* encode the natively read values into the requested data type */
+            if (readDataType != targetDataType)
+            {
+                var targetRequest = new ReadRequest(
+                    catalogItem,
+                    targetData.AsMemory(0, elementCount * targetElementSize),
+                    request.Status)
+                {
+                    RequestedDataType = targetDataType
+                };
+
+                ConvertInto(targetRequest.Data.Span, request.Data.Span, readDataType, targetDataType, byteOrder);
+
+                if (request.CoveredRange is { } coveredRange)
+                    targetRequest.ReportCoveredRange(coveredRange.Begin, coveredRange.End);
+
+                request = targetRequest;
+            }
+
+            yield return new ReadChunk(currentBegin, currentEnd, request);
+
+            currentBegin = currentEnd;
+        }
+    }
+
     internal static int CalculateElementCount(DateTime begin, DateTime end, TimeSpan samplePeriod)
     {
         return (int)((end.Ticks - begin.Ticks) / samplePeriod.Ticks);
@@ -0,0 +1,29 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using Microsoft.Extensions.Logging;
+
+namespace Nexus.Extensibility;
+
+/// <summary>
+/// Contains extension methods to make life easier working with <see cref="ILogger"/>.
+/// </summary>
+public static class LoggingExtensions
+{
+    /// <summary>
+    /// Logs a message and attaches the provided fields as structured properties instead of concatenating them into the message.
+    /// </summary>
+    /// <param name="logger">The logger.</param>
+    /// <param name="logLevel">The log level.</param>
+    /// <param name="message">The message.</param>
+    /// <param name="fields">The structured fields to attach.</param>
+    public static void LogWithFields(
+        this ILogger logger,
+        LogLevel logLevel,
+        string message,
+        IReadOnlyDictionary<string, object?> fields)
+    {
+        using var scope = logger.BeginScope(fields);
+        logger.Log(logLevel, "{Message}", message);
+    }
+}
@@ -1,6 +1,7 @@
 ﻿// MIT License
 // Copyright (c) [2024] [nexus-main]
 
+using Microsoft.Extensions.Logging;
 using Microsoft.Extensions.Logging.Abstractions;
 using Moq;
 using Nexus.Core;
@@ -11,6 +12,7 @@ using Nexus.Sources;
 using System.Collections.Concurrent;
 using System.IO.Pipelines;
 using System.Runtime.InteropServices;
+using System.Text.Json;
 using Xunit;
 
 namespace DataSource;
@@ -336,6 +338,236 @@ public class DataSourceControllerTests(DataSourceControllerFixture fixture)
                2), Times.Exactly(1));
     }
 
+    [Fact]
+    public async Task CanReadAggregatedByFallback()
+    {
+        // Arrange
+        var processingService = new Mock<IProcessingService>();
+
+        using var controller = new DataSourceController(
+            _fixture.DataSource,
+            _fixture.Registration,
+            default!,
+            default!,
+            processingService.Object,
+            default!,
+            new DataOptions() { CachePattern = "^$" },
+            NullLogger<DataSourceController>.Instance);
+
+        await controller.InitializeAsync(new ConcurrentDictionary<string, ResourceCatalog>(), default!, CancellationToken.None);
+
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var end = new DateTime(2020, 01, 01, 0, 10, 0, DateTimeKind.Utc);
+        var pipe = new Pipe();
+        var baseItem = (await controller.GetCatalogAsync(Sample.LocalCatalogId, CancellationToken.None)).Find("/SAMPLE/LOCAL/T1/1_s");
+
+        var item = baseItem with
+        {
+            Representation = new Representation(
+                NexusDataType.FLOAT64,
+                TimeSpan.FromMinutes(1),
+                parameters: default,
+                RepresentationKind.Mean)
+        };
+
+        var catalogItemRequest = new CatalogItemRequest(item, baseItem, default!);
+
+        var memoryTracker = Mock.Of<IMemoryTracker>();
+
+        Mock.Get(memoryTracker)
+            .Setup(memoryTracker => memoryTracker.RegisterAllocationAsync(It.IsAny<long>(), It.IsAny<long>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new AllocationRegistration(memoryTracker, actualByteCount: 20000));
+
+        // Act
+        await controller.ReadSingleAsync(
+            begin,
+            end,
+            catalogItemRequest,
+            pipe.Writer,
+            default!,
+            memoryTracker,
+            new Progress<double>(),
+            NullLogger<DataSourceController>.Instance,
+            CancellationToken.None);
+
+        // Assert
+        processingService
+            .Verify(processingService => processingService.Aggregate(
+               NexusDataType.FLOAT64,
+               RepresentationKind.Mean,
+               It.IsAny<Memory<byte>>(),
+               It.IsAny<ReadOnlyMemory<byte>>(),
+               It.IsAny<Memory<double>>(),
+               60), Times.Exactly(1));
+    }
+
+    [Fact]
+    public async Task CanReadAggregatedDirectly()
+    {
+        // Arrange
+        var processingService = new Mock<IProcessingService>();
+        var dataSource = new ResamplingSample();
+
+        using var controller = new DataSourceController(
+            dataSource,
+            _fixture.Registration,
+            default!,
+            default!,
+            processingService.Object,
+            default!,
+            new DataOptions() { CachePattern = "^$" },
+            NullLogger<DataSourceController>.Instance);
+
+        await controller.InitializeAsync(new ConcurrentDictionary<string, ResourceCatalog>(), default!, CancellationToken.None);
+
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var end = new DateTime(2020, 01, 01, 0, 10, 0, DateTimeKind.Utc);
+        var pipe = new Pipe();
+        var baseItem = (await controller.GetCatalogAsync(Sample.LocalCatalogId, CancellationToken.None)).Find("/SAMPLE/LOCAL/T1/1_s");
+
+        var item = baseItem with
+        {
+            Representation = new Representation(
+                NexusDataType.FLOAT64,
+                TimeSpan.FromMinutes(1),
+                parameters: default,
+                RepresentationKind.Mean)
+        };
+
+        var catalogItemRequest = new CatalogItemRequest(item, baseItem, default!);
+
+        var memoryTracker = Mock.Of<IMemoryTracker>();
+
+        Mock.Get(memoryTracker)
+            .Setup(memoryTracker => memoryTracker.RegisterAllocationAsync(It.IsAny<long>(), It.IsAny<long>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new AllocationRegistration(memoryTracker, actualByteCount: 20000));
+
+        // Act
+        await controller.ReadSingleAsync(
+            begin,
+            end,
+            catalogItemRequest,
+            pipe.Writer,
+            default!,
+            memoryTracker,
+            new Progress<double>(),
+            NullLogger<DataSourceController>.Instance,
+            CancellationToken.None);
+
+        // Assert
+        Assert.Equal(new string[] { "1_min_mean" }, dataSource.RepresentationIds);
+
+        processingService
+            .Verify(processingService => processingService.Aggregate(
+               It.IsAny<NexusDataType>(),
+               It.IsAny<RepresentationKind>(),
+               It.IsAny<Memory<byte>>(),
+               It.IsAny<ReadOnlyMemory<byte>>(),
+               It.IsAny<Memory<double>>(),
+               It.IsAny<int>()), Times.Never());
+
+        var result = await pipe.Reader.ReadAsync();
+        Assert.Equal(10 * sizeof(double), result.Buffer.Length);
+    }
+
+    [Fact]
+    public async Task CanReadAggregatedDirectlyCached()
+    {
+        // Arrange
+        var dataSource = new ResamplingSample();
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var end = new DateTime(2020, 01, 01, 0, 10, 0, DateTimeKind.Utc);
+
+        var uncachedIntervals = new List<Interval>
+        {
+            new(new DateTime(2020, 01, 01, 0, 5, 0, DateTimeKind.Utc), end)
+        };
+
+        var cacheService = new Mock<ICacheService>();
+
+        cacheService
+            .Setup(cacheService => cacheService.ReadAsync(
+               It.IsAny<CatalogItem>(),
+               It.IsAny<DateTime>(),
+               It.IsAny<Memory<double>>(),
+               It.IsAny<CancellationToken>())
+            )
+            .Callback<CatalogItem, DateTime, Memory<double>, CancellationToken>((item, begin, targetBuffer, cancellationToken) =>
+            {
+                targetBuffer.Span[..5].Fill(-1);
+            })
+            .Returns(Task.FromResult(uncachedIntervals));
+
+        using var controller = new DataSourceController(
+            dataSource,
+            _fixture.Registration,
+            default!,
+            default!,
+            default!,
+            cacheService.Object,
+            new DataOptions(),
+            NullLogger<DataSourceController>.Instance);
+
+        await controller.InitializeAsync(new ConcurrentDictionary<string, ResourceCatalog>(), default!, CancellationToken.None);
+
+        var pipe = new Pipe();
+        var baseItem = (await controller.GetCatalogAsync(Sample.LocalCatalogId, CancellationToken.None)).Find("/SAMPLE/LOCAL/T1/1_s");
+
+        var item = baseItem with
+        {
+            Representation = new Representation(
+                NexusDataType.FLOAT64,
+                TimeSpan.FromMinutes(1),
+                parameters: default,
+                RepresentationKind.Mean)
+        };
+
+        var catalogItemRequest = new CatalogItemRequest(item, baseItem, default!);
+
+        var memoryTracker = Mock.Of<IMemoryTracker>();
+
+        Mock.Get(memoryTracker)
+            .Setup(memoryTracker => memoryTracker.RegisterAllocationAsync(It.IsAny<long>(), It.IsAny<long>(), It.IsAny<CancellationToken>()))
+            .ReturnsAsync(new AllocationRegistration(memoryTracker, actualByteCount: 20000));
+
+        // Act
+        await controller.ReadSingleAsync(
+            begin,
+            end,
+            catalogItemRequest,
+            pipe.Writer,
+            default!,
+            memoryTracker,
+            new Progress<double>(),
+            NullLogger<DataSourceController>.Instance,
+            CancellationToken.None);
+
+        // Assert
+        Assert.Equal(new string[] { "1_min_mean" }, dataSource.RepresentationIds);
+        Assert.Equal(new[] { (uncachedIntervals[0].Begin, uncachedIntervals[0].End) }, dataSource.ReadPeriods);
+
+        var result = await pipe.Reader.ReadAsync();
+        var actual = MemoryMarshal.Cast<byte, double>(result.Buffer.First.Span).ToArray();
+
+        Assert.Equal(10, actual.Length);
+        Assert.All(actual[..5], value => Assert.Equal(-1, value));
+
+        cacheService
+            .Verify(cacheService => cacheService.ReadAsync(
+               item,
+               begin,
+               It.IsAny<Memory<double>>(),
+               It.IsAny<CancellationToken>()), Times.Once());
+
+        cacheService
+            .Verify(cacheService => cacheService.UpdateAsync(
+               item,
+               begin,
+               It.IsAny<Memory<double>>(),
+               uncachedIntervals,
+               It.IsAny<CancellationToken>()), Times.Once());
+    }
+
     [Fact]
     public async Task CanReadCached()
     {
@@ -525,4 +757,85 @@ public class DataSourceControllerTests(DataSourceControllerFixture fixture)
                uncachedIntervals,
                It.IsAny<CancellationToken>()), Times.Once());
     }
+
+    [Fact]
+    public async Task CanUpgradeSourceConfigurationDuringInitialization()
+    {
+        // Arrange
+        var dataSource = new VersionedSample();
+
+        var registration = new InternalDataSourceRegistration(
+            Id: Guid.NewGuid(),
+            "a",
+            new Uri("http://xyz"),
+            Configuration: new Dictionary<string, JsonElement>()
+            {
+                [ExtensibilityUtilities.ConfigurationVersionKey] = JsonSerializer.SerializeToElement(1)
+            });
+
+        using var controller = new DataSourceController(
+            dataSource,
+            registration,
+            default!,
+            default!,
+            default!,
+            default!,
+            default!,
+            NullLogger<DataSourceController>.Instance);
+
+        // Act
+        await controller.InitializeAsync(default!, NullLogger.Instance, CancellationToken.None);
+
+        // Assert
+        var version = dataSource.Context!.SourceConfiguration![ExtensibilityUtilities.ConfigurationVersionKey].GetInt32();
+
+        Assert.Equal(2, version);
+        Assert.Equal(1, registration.Configuration![ExtensibilityUtilities.ConfigurationVersionKey].GetInt32());
+    }
+
+    private class VersionedSample : Sample, IDataSource, IVersionedConfiguration
+    {
+        public DataSourceContext? Context { get; private set; }
+
+        public int CurrentVersion => 2;
+
+        public IReadOnlyDictionary<string, JsonElement> Migrate(int fromVersion, IReadOnlyDictionary<string, JsonElement> configuration)
+        {
+            return configuration;
+        }
+
+        public new Task SetContextAsync(
+            DataSourceContext context,
+            ILogger logger,
+            CancellationToken cancellationToken)
+        {
+            Context = context;
+            return base.SetContextAsync(context, logger, cancellationToken);
+        }
+    }
+
+    private class ResamplingSample : Sample, IDataSource
+    {
+        public List<string> RepresentationIds { get; } = [];
+
+        public List<(DateTime Begin, DateTime End)> ReadPeriods { get; } = [];
+
+        public bool CanResample(string catalogId, string resourceId)
+        {
+            return true;
+        }
+
+        public new Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            RepresentationIds.AddRange(requests.Select(request => request.CatalogItem.Representation.Id));
+            ReadPeriods.Add((begin, end));
+            return base.ReadAsync(begin, end, requests, readData, progress, cancellationToken);
+        }
+    }
 }
@@ -5,6 +5,8 @@ using Microsoft.Extensions.Logging;
 using Nexus.Core;
 using Nexus.Services;
 using Serilog;
+using Serilog.Core;
+using Serilog.Events;
 using Serilog.Extensions.Logging;
 using System.Text.RegularExpressions;
 using Xunit;
@@ -23,6 +25,36 @@ namespace Other;
 
 public class LoggingTests
 {
+    [Theory]
+    [InlineData(LogLevel.Trace, LogEventLevel.Verbose)]
+    [InlineData(LogLevel.Debug, LogEventLevel.Debug)]
+    [InlineData(LogLevel.Information, LogEventLevel.Information)]
+    [InlineData(LogLevel.Warning, LogEventLevel.Warning)]
+    [InlineData(LogLevel.Error, LogEventLevel.Error)]
+    [InlineData(LogLevel.Critical, LogEventLevel.Fatal)]
+    public void CanMapExtensionLogLevels(LogLevel logLevel, LogEventLevel expected)
+    {
+        // Arrange
+        var sink = new CollectingSink();
+
+        using var serilogger = new LoggerConfiguration()
+            .MinimumLevel.Verbose()
+            .WriteTo.Sink(sink)
+            .CreateLogger();
+
+        using var loggerFactory = new SerilogLoggerFactory(serilogger);
+
+        /* extensions only see the Microsoft.Extensions.Logging facade */
+        var logger = loggerFactory.CreateLogger("Extension");
+
+        // Act
+        logger.Log(logLevel, "Hello");
+
+        // Assert
+        var logEvent = Assert.Single(sink.LogEvents);
+        Assert.Equal(expected, logEvent.Level);
+    }
+
     [Fact]
     public void CanSerilog()
     {
@@ -149,4 +181,14 @@ public class LoggingTests
             //
         }
     }
+
+    private class CollectingSink : ILogEventSink
+    {
+        public List<LogEvent> LogEvents { get; } = [];
+
+        public void Emit(LogEvent logEvent)
+        {
+            LogEvents.Add(logEvent);
+        }
+    }
 }
\ No newline at end of file
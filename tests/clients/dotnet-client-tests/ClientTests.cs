@@ -20,7 +20,7 @@ public class ClientTests
         // Arrange
         var messageHandlerMock = new Mock<HttpMessageHandler>();
         var catalogId = "my-catalog-id";
-        var expectedCatalog = new ResourceCatalog(Id: catalogId, default, default);
+        var expectedCatalog = new ResourceCatalog(Id: catalogId, default, default, default);
 
         var actualHeaders = new List<IEnumerable<string>?>();
 
@@ -2,6 +2,7 @@
 // Copyright (c) [2024] [nexus-main]
 
 using Nexus.DataModel;
+using System.Text.Json;
 using Xunit;
 
 namespace Nexus.Extensibility.Tests;
@@ -28,6 +29,11 @@ public class DataModelExtensionsTests
 
     [InlineData("00:01:00.0000000", "1_min")]
     [InlineData("00:15:00.0000000", "15_min")]
+
+    [InlineData("1.12:00:00.0000000", "36_h")]
+    [InlineData("1.00:00:01.0000000", "86401_s")]
+    [InlineData("14.00:00:00.0000000", "14_d")]
+    [InlineData("400.00:00:00.0000000", "400_d")]
     public void CanCreateUnitStrings(string periodString, string expected)
     {
         var actual = TimeSpan
@@ -37,6 +43,32 @@ public class DataModelExtensionsTests
         Assert.Equal(expected, actual);
     }
 
+    [Theory]
+    [InlineData(900L, "900_ns")]
+    [InlineData(1_000L, "1_us")]
+    [InlineData(999_900L, "999900_ns")]
+    [InlineData(1_000_000L, "1_ms")]
+    [InlineData(1_000_000_000L, "1_s")]
+    [InlineData(59_000_000_000L, "59_s")]
+    [InlineData(60_000_000_000L, "1_min")]
+    [InlineData(3_599_000_000_000L, "3599_s")]
+    [InlineData(3_600_000_000_000L, "1_h")]
+    [InlineData(82_800_000_000_000L, "23_h")]
+    [InlineData(86_400_000_000_000L, "1_d")]
+    [InlineData(86_400_000_000_000L * 3000, "3000_d")]
+    public void CanCreateUnitStringsAtUnitBoundaries(long nanoseconds, string expected)
+    {
+        // Arrange
+        var samplePeriod = TimeSpan.FromTicks(nanoseconds / 100);
+
+        // Act
+        var actual = samplePeriod.ToUnitString();
+
+        // Assert
+        Assert.Equal(expected, actual);
+        Assert.Equal(samplePeriod, DataModelExtensions.ToSamplePeriod(actual));
+    }
+
     [Theory]
     [InlineData("100_ns", "00:00:00.0000001")]
     [InlineData("200_ns", "00:00:00.0000002")]
@@ -57,6 +89,9 @@ public class DataModelExtensionsTests
 
     [InlineData("1_min", "00:01:00.0000000")]
     [InlineData("15_min", "00:15:00.0000000")]
+
+    [InlineData("36_h", "1.12:00:00.0000000")]
+    [InlineData("14_d", "14.00:00:00.0000000")]
     public void CanParseUnitStrings(string unitString, string expectedPeriodString)
     {
         var expected = TimeSpan
@@ -67,6 +102,727 @@ public class DataModelExtensionsTests
         Assert.Equal(expected, actual);
     }
 
+    [Theory]
+    [InlineData("00:00:00.0000001")]
+    [InlineData("00:00:00.0000015")]
+    [InlineData("00:00:00.0015000")]
+    [InlineData("00:00:01.5000000")]
+    [InlineData("00:15:00.0000000")]
+    [InlineData("1.12:00:00.0000000")]
+    [InlineData("1.00:00:01.0000000")]
+    [InlineData("14.00:00:00.0000000")]
+    [InlineData("400.00:00:00.0000000")]
+    public void UnitStringsRoundTrip(string periodString)
+    {
+        // Arrange
+        var expected = TimeSpan.Parse(periodString);
+
+        // Act
+        var actual = DataModelExtensions.ToSamplePeriod(expected.ToUnitString());
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Theory]
+    [InlineData("10ms")]
+    [InlineData("10_xs")]
+    [InlineData("ab_s")]
+    [InlineData("0_s")]
+    [InlineData("1_ns")]
+    public void ToSamplePeriodThrowsForInvalidUnitString(string unitString)
+    {
+        // Act
+        void action() => DataModelExtensions.ToSamplePeriod(unitString);
+
+        // Assert
+        Assert.Throws<Exception>(action);
+    }
+
+    [Theory]
+    [InlineData("00:00:00.0000001", 100UL)]
+    [InlineData("00:00:00.0010000", 1_000_000UL)]
+    [InlineData("00:00:01.0000000", 1_000_000_000UL)]
+    [InlineData("00:01:00.0000000", 60_000_000_000UL)]
+    [InlineData("1.00:00:00.0000000", 86_400_000_000_000UL)]
+    public void CanRoundtripNanoseconds(string periodString, ulong expected)
+    {
+        var samplePeriod = TimeSpan
+            .Parse(periodString);
+
+        var actual = samplePeriod.ToNanoseconds();
+
+        Assert.Equal(expected, actual);
+        Assert.Equal(samplePeriod, DataModelExtensions.FromNanoseconds(actual));
+    }
+
+    [Theory]
+    [InlineData(1L, "100_ns")]
+    [InlineData(901L, "90100_ns")]
+    [InlineData(10_000_001L, "1000000100_ns")]
+    [InlineData(36_000_010_000L, "3600001_ms")]
+    [InlineData(864_000_000_000L + 1L, "86400000000100_ns")]
+    public void AwkwardPeriodsAreExpressible(long ticks, string expected)
+    {
+        var samplePeriod = TimeSpan.FromTicks(ticks);
+
+        Assert.True(samplePeriod.IsExpressible());
+
+        var unitString = samplePeriod.ToUnitString();
+
+        Assert.Equal(expected, unitString);
+        Assert.Equal(samplePeriod, DataModelExtensions.ToSamplePeriod(unitString));
+    }
+
+    [Fact]
+    public void NonPositiveAndHugePeriodsAreNotExpressible()
+    {
+        Assert.False(TimeSpan.Zero.IsExpressible());
+        Assert.False(TimeSpan.FromSeconds(-1).IsExpressible());
+        Assert.False(TimeSpan.MaxValue.IsExpressible());
+    }
+
+    [Fact]
+    public void ToUnitStringThrowsForOverRangePeriod()
+    {
+        // Arrange
+        var samplePeriod = TimeSpan.FromDays(365 * 300);
+
+        // Act
+        void action() => samplePeriod.ToUnitString();
+
+        // Assert
+        Assert.False(samplePeriod.IsExpressible());
+        Assert.Throws<OverflowException>(action);
+    }
+
+    [Theory]
+    [InlineData("10 min", 600L)]
+    [InlineData("10min", 600L)]
+    [InlineData("10_min", 600L)]
+    [InlineData(" 1 s ", 1L)]
+    [InlineData("1 S", 1L)]
+    public void CanParseSamplePeriodLenient(string input, long expectedSeconds)
+    {
+        // Act
+        var status = DataModelExtensions.ParseSamplePeriodLenient(input, out var actual);
+
+        // Assert
+        Assert.Equal(SamplePeriodParseStatus.Success, status);
+        Assert.Equal(TimeSpan.FromSeconds(expectedSeconds), actual);
+    }
+
+    [Theory]
+    [InlineData("", SamplePeriodParseStatus.Incomplete)]
+    [InlineData("10", SamplePeriodParseStatus.Incomplete)]
+    [InlineData("10 ", SamplePeriodParseStatus.Incomplete)]
+    [InlineData("10 mi", SamplePeriodParseStatus.Incomplete)]
+    [InlineData("10 x", SamplePeriodParseStatus.Invalid)]
+    [InlineData("min", SamplePeriodParseStatus.Invalid)]
+    [InlineData("0 s", SamplePeriodParseStatus.Invalid)]
+    [InlineData("1 ns", SamplePeriodParseStatus.Invalid)]
+    public void ParseSamplePeriodLenientReportsIncompleteAndInvalidInput(string input, SamplePeriodParseStatus expected)
+    {
+        // Act
+        var actual = DataModelExtensions.ParseSamplePeriodLenient(input, out _);
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Theory]
+    [InlineData((ushort)0x340, NexusDataType.FLOAT64)]
+    [InlineData((ushort)0x108, NexusDataType.UINT8)]
+    [InlineData((ushort)0x210, NexusDataType.INT16)]
+    public void CanConvertDiscriminantToDataType(ushort value, NexusDataType expected)
+    {
+        var success = DataModelExtensions.TryToNexusDataType(value, out var actual);
+
+        Assert.True(success);
+        Assert.Equal(expected, actual);
+    }
+
+    [Theory]
+    [InlineData((ushort)0)]
+    [InlineData((ushort)0x341)]
+    public void CannotConvertUnknownDiscriminantToDataType(ushort value)
+    {
+        Assert.False(DataModelExtensions.TryToNexusDataType(value, out _));
+    }
+
+    [Theory]
+    [InlineData("FLOAT64", NexusDataType.FLOAT64, true)]
+    [InlineData("uint8", NexusDataType.UINT8, true)]
+    [InlineData("Int16", NexusDataType.INT16, true)]
+    [InlineData("832", default(NexusDataType), false)]
+    [InlineData("DOUBLE", default(NexusDataType), false)]
+    public void CanConvertNameToDataType(string name, NexusDataType expected, bool expectedSuccess)
+    {
+        var success = DataModelExtensions.TryToNexusDataType(name, out var actual);
+
+        Assert.Equal(expectedSuccess, success);
+        Assert.Equal(expected, actual);
+
+        if (success)
+            Assert.Equal(name.ToUpperInvariant(), actual.ToString());
+    }
+
+    [Theory]
+    [InlineData(NexusDataType.UINT8, 1, false, true, false)]
+    [InlineData(NexusDataType.INT8, 1, true, true, false)]
+    [InlineData(NexusDataType.UINT16, 2, false, true, false)]
+    [InlineData(NexusDataType.INT16, 2, true, true, false)]
+    [InlineData(NexusDataType.UINT32, 4, false, true, false)]
+    [InlineData(NexusDataType.INT32, 4, true, true, false)]
+    [InlineData(NexusDataType.UINT64, 8, false, true, false)]
+    [InlineData(NexusDataType.INT64, 8, true, true, false)]
+    [InlineData(NexusDataType.FLOAT32, 4, true, false, true)]
+    [InlineData(NexusDataType.FLOAT64, 8, true, false, true)]
+    public void CanClassifyDataTypes(NexusDataType dataType, int expectedSize, bool isSigned, bool isInteger, bool isFloatingPoint)
+    {
+        Assert.Equal(expectedSize, dataType.GetElementSize());
+        Assert.Equal(isSigned, dataType.IsSigned());
+        Assert.Equal(isInteger, dataType.IsInteger());
+        Assert.Equal(isFloatingPoint, dataType.IsFloatingPoint());
+        Assert.Equal(expectedSize, new Representation(dataType, TimeSpan.FromSeconds(1)).ElementSize);
+    }
+
+    [Fact]
+    public void PeriodInputStatePreservesValueWhileTyping()
+    {
+        // Arrange
+        var state = new PeriodInputState(TimeSpan.FromSeconds(1));
+
+        var keystrokes = new (string Text, SamplePeriodParseStatus Status, TimeSpan Value)[]
+        {
+            ("", SamplePeriodParseStatus.Incomplete, TimeSpan.FromSeconds(1)),
+            ("1", SamplePeriodParseStatus.Incomplete, TimeSpan.FromSeconds(1)),
+            ("10", SamplePeriodParseStatus.Incomplete, TimeSpan.FromSeconds(1)),
+            ("10 ", SamplePeriodParseStatus.Incomplete, TimeSpan.FromSeconds(1)),
+            ("10 m", SamplePeriodParseStatus.Incomplete, TimeSpan.FromSeconds(1)),
+            ("10 mi", SamplePeriodParseStatus.Incomplete, TimeSpan.FromSeconds(1)),
+            ("10 min", SamplePeriodParseStatus.Success, TimeSpan.FromMinutes(10)),
+            ("10 minx", SamplePeriodParseStatus.Invalid, TimeSpan.FromMinutes(10))
+        };
+
+        foreach (var (text, expectedStatus, expectedValue) in keystrokes)
+        {
+            // Act
+            var actualStatus = state.Update(text);
+
+            // Assert
+            Assert.Equal(expectedStatus, actualStatus);
+            Assert.Equal(expectedStatus, state.Status);
+            Assert.Equal(expectedValue, state.Value);
+        }
+    }
+
+    [Theory]
+    [InlineData("1_s", "1_min", 60L)]
+    [InlineData("1_s", "1_s", 1L)]
+    [InlineData("100_ms", "1_d", 864_000L)]
+    [InlineData("7_s", "1_min", null)]
+    [InlineData("1_min", "1_s", null)]
+    public void CanGetFactorToCoarserPeriod(string unitString, string coarserUnitString, long? expected)
+    {
+        // Arrange
+        var samplePeriod = DataModelExtensions.ToSamplePeriod(unitString);
+        var coarserSamplePeriod = DataModelExtensions.ToSamplePeriod(coarserUnitString);
+
+        // Act
+        var actual = samplePeriod.GetFactorTo(coarserSamplePeriod);
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Theory]
+    [InlineData("1333_ms", "1_s")]
+    [InlineData("7_s", "10_s")]
+    [InlineData("30_s", "10_s")]
+    [InlineData("45_s", "1_min")]
+    [InlineData("1_s", "1_s")]
+    [InlineData("10_d", "1_d")]
+    public void CanSnapToNearestCanonicalPeriod(string unitString, string expected)
+    {
+        var actual = DataModelExtensions
+            .ToSamplePeriod(unitString)
+            .ToNearestCanonical()
+            .ToUnitString();
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void ToNearestCanonicalHandlesPeriodsOutOfRange()
+    {
+        Assert.Equal(TimeSpan.FromDays(1), TimeSpan.MaxValue.ToNearestCanonical());
+        Assert.Throws<ArgumentException>(() => TimeSpan.Zero.ToNearestCanonical());
+        Assert.Throws<ArgumentException>(() => TimeSpan.MinValue.ToNearestCanonical());
+    }
+
+    [Theory]
+    [InlineData(0UL)]
+    [InlineData(150UL)]
+    public void FromNanosecondsThrowsForInvalidValues(ulong nanoseconds)
+    {
+        Assert.Throws<ArgumentException>(() => DataModelExtensions.FromNanoseconds(nanoseconds));
+    }
+
+    [Fact]
+    public void ToNanosecondsThrowsForPeriodsOutOfRange()
+    {
+        Assert.Throws<ArgumentException>(() => TimeSpan.MaxValue.ToNanoseconds());
+        Assert.Throws<ArgumentException>(() => TimeSpan.Zero.ToNanoseconds());
+    }
+
+    [Theory]
+    [InlineData(NexusDataType.UINT8, ByteOrder.LittleEndian, 129d)]
+    [InlineData(NexusDataType.UINT8, ByteOrder.BigEndian, 129d)]
+    [InlineData(NexusDataType.INT8, ByteOrder.LittleEndian, -127d)]
+    [InlineData(NexusDataType.INT8, ByteOrder.BigEndian, -127d)]
+    [InlineData(NexusDataType.UINT16, ByteOrder.LittleEndian, 641d)]
+    [InlineData(NexusDataType.UINT16, ByteOrder.BigEndian, 33026d)]
+    [InlineData(NexusDataType.INT16, ByteOrder.LittleEndian, 641d)]
+    [InlineData(NexusDataType.INT16, ByteOrder.BigEndian, -32510d)]
+    [InlineData(NexusDataType.UINT32, ByteOrder.LittleEndian, 67306113d)]
+    [InlineData(NexusDataType.UINT32, ByteOrder.BigEndian, 2164392708d)]
+    [InlineData(NexusDataType.INT32, ByteOrder.LittleEndian, 67306113d)]
+    [InlineData(NexusDataType.INT32, ByteOrder.BigEndian, -2130574588d)]
+    [InlineData(NexusDataType.UINT64, ByteOrder.LittleEndian, 9801809732607083137d)]
+    [InlineData(NexusDataType.UINT64, ByteOrder.BigEndian, 9295995896645158792d)]
+    [InlineData(NexusDataType.INT64, ByteOrder.LittleEndian, -8644934341102468479d)]
+    [InlineData(NexusDataType.INT64, ByteOrder.BigEndian, -9150748177064392824d)]
+    [InlineData(NexusDataType.FLOAT32, ByteOrder.LittleEndian, 1.5400125733135976e-36)]
+    [InlineData(NexusDataType.FLOAT32, ByteOrder.BigEndian, -2.387939260590663e-38)]
+    [InlineData(NexusDataType.FLOAT64, ByteOrder.LittleEndian, -5.447603722011713e-270)]
+    [InlineData(NexusDataType.FLOAT64, ByteOrder.BigEndian, -8.207880399132047e-304)]
+    public void CanDecodeDouble(NexusDataType dataType, ByteOrder byteOrder, double expected)
+    {
+        var bytes = new byte[] { 0x81, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x88 };
+        var actual = dataType.DecodeDouble(bytes, byteOrder);
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Theory]
+    [InlineData(NexusDataType.UINT8)]
+    [InlineData(NexusDataType.INT8)]
+    [InlineData(NexusDataType.UINT16)]
+    [InlineData(NexusDataType.INT16)]
+    [InlineData(NexusDataType.UINT32)]
+    [InlineData(NexusDataType.INT32)]
+    [InlineData(NexusDataType.UINT64)]
+    [InlineData(NexusDataType.INT64)]
+    [InlineData(NexusDataType.FLOAT32)]
+    [InlineData(NexusDataType.FLOAT64)]
+    public void CanEncodeDouble(NexusDataType dataType)
+    {
+        foreach (var byteOrder in new[] { ByteOrder.LittleEndian, ByteOrder.BigEndian })
+        {
+            var bytes = new byte[8];
+
+            dataType.EncodeDouble(99, bytes, byteOrder);
+            var actual = dataType.DecodeDouble(bytes, byteOrder);
+
+            Assert.Equal(99, actual);
+        }
+    }
+
+    [Fact]
+    public void EncodeDoubleClampsIntegerTypes()
+    {
+        var bytes = new byte[1];
+
+        NexusDataType.UINT8.EncodeDouble(300, bytes);
+        Assert.Equal(255, bytes[0]);
+
+        NexusDataType.UINT8.EncodeDouble(-5, bytes);
+        Assert.Equal(0, bytes[0]);
+    }
+
+    [Fact]
+    public void EncodeDoubleCanRejectInfinity()
+    {
+        var bytes = new byte[8];
+
+        NexusDataType.FLOAT64.EncodeDouble(double.PositiveInfinity, bytes);
+        Assert.Equal(double.PositiveInfinity, NexusDataType.FLOAT64.DecodeDouble(bytes));
+
+        Assert.Throws<ArgumentException>(() => NexusDataType.FLOAT64.EncodeDouble(double.PositiveInfinity, bytes, allowInfinity: false));
+    }
+
+    [Fact]
+    public void EncodeDoublePreservesNaNForFloatTypes()
+    {
+        var bytes = new byte[4];
+
+        NexusDataType.FLOAT32.EncodeDouble(double.NaN, bytes);
+        var actual = NexusDataType.FLOAT32.DecodeDouble(bytes);
+
+        Assert.True(double.IsNaN(actual));
+    }
+
+    [Theory]
+    [InlineData(new[] { " a ", "b" }, new[] { "a", "b" })]
+    [InlineData(new[] { "a", "b", "a" }, new[] { "a", "b" })]
+    [InlineData(new[] { "", "  ", "a" }, new[] { "a" })]
+    [InlineData(new[] { " a ", "a " }, new[] { "a" })]
+    public void CanNormalizeGroups(string[] groups, string[] expected)
+    {
+        var resource = new ResourceBuilder(id: "Resource1")
+            .WithGroups(groups)
+            .Build();
+
+        var actual = resource.GetGroups();
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void CanSetAndGetRange()
+    {
+        var resource = new ResourceBuilder(id: "Resource1")
+            .WithRange(0, 100)
+            .Build();
+
+        Assert.Equal((0.0, 100.0), resource.GetRange());
+        Assert.Null(new Resource(id: "Resource1").GetRange());
+    }
+
+    [Fact]
+    public void WithRangeThrowsForInvertedRange()
+    {
+        var builder = new ResourceBuilder(id: "Resource1");
+
+        Assert.Throws<ArgumentException>(() => builder.WithRange(100, 0));
+    }
+
+    [Fact]
+    public void CanSetEnumMap()
+    {
+        // Arrange
+        var expected = new Dictionary<long, string>()
+        {
+            [2] = "fault",
+            [0] = "off",
+            [1] = "on"
+        };
+
+        // Act
+        var representation = new Representation(NexusDataType.UINT8, TimeSpan.FromSeconds(1))
+            .WithEnumMap(expected);
+
+        // Assert
+        Assert.Equal("0=off,1=on,2=fault", representation.Properties!.GetStringValue(DataModelExtensions.EnumMapKey));
+        Assert.Equal(expected.OrderBy(entry => entry.Key), representation.GetEnumMap()!.OrderBy(entry => entry.Key));
+        Assert.Null(new Representation(NexusDataType.UINT8, TimeSpan.FromSeconds(1)).GetEnumMap());
+    }
+
+    [Theory]
+    [InlineData("on,off")]
+    [InlineData("on=1")]
+    [InlineData(" on")]
+    [InlineData("")]
+    public void WithEnumMapThrowsForInvalidLabel(string label)
+    {
+        // Arrange
+        var representation = new Representation(NexusDataType.UINT8, TimeSpan.FromSeconds(1));
+        var enumMap = new Dictionary<long, string>() { [0] = "off", [1] = label };
+
+        // Act
+        void action() => representation.WithEnumMap(enumMap);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+        Assert.Throws<ArgumentException>(() => representation.WithEnumMap(new Dictionary<long, string>()));
+    }
+
+    [Fact]
+    public void CanRoundtripEnumMap()
+    {
+        // Arrange
+        var expected = new Dictionary<long, string>()
+        {
+            [-1] = "not available",
+            [long.MaxValue] = "saturated"
+        };
+
+        // Act
+        var representation = new Representation(NexusDataType.INT64, TimeSpan.FromSeconds(1))
+            .WithEnumMap(expected);
+
+        var actual = representation.GetEnumMap();
+
+        // Assert
+        Assert.Equal(expected.OrderBy(entry => entry.Key), actual!.OrderBy(entry => entry.Key));
+    }
+
+    [Theory]
+    [InlineData("0=off,1")]
+    [InlineData("0=off,x=on")]
+    [InlineData("0=off,1=")]
+    [InlineData("0=off,0=on")]
+    public void GetEnumMapThrowsForMalformedMap(string enumMapString)
+    {
+        // Arrange
+        var representation = new Representation(
+            NexusDataType.UINT8,
+            TimeSpan.FromSeconds(1),
+            properties: new Dictionary<string, JsonElement>()
+            {
+                [DataModelExtensions.EnumMapKey] = JsonSerializer.SerializeToElement(enumMapString)
+            });
+
+        // Act
+        void action() => representation.GetEnumMap();
+
+        // Assert
+        Assert.Throws<Exception>(action);
+    }
+
+    [Theory]
+    [InlineData(InterpolationMode.None)]
+    [InlineData(InterpolationMode.Linear)]
+    [InlineData(InterpolationMode.Step)]
+    public void CanSetInterpolationMode(InterpolationMode expected)
+    {
+        var representation = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1))
+            .WithInterpolation(expected);
+
+        var actual = representation.GetInterpolation();
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void InterpolationModeDefaultsToNone()
+    {
+        var representation = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1));
+
+        Assert.Equal(InterpolationMode.None, representation.GetInterpolation());
+    }
+
+    [Fact]
+    public void CanDecodeFixedPointValue()
+    {
+        // Arrange
+        var representation = new Representation(NexusDataType.INT16, TimeSpan.FromSeconds(1))
+            .WithScaling(scale: 0.1, offset: 100);
+
+        var bytes = new byte[2];
+        BitConverter.TryWriteBytes(bytes, (short)-250);
+
+        // Act
+        var actual = representation.DecodeDouble(bytes, BitConverter.IsLittleEndian ? ByteOrder.LittleEndian : ByteOrder.BigEndian);
+
+        // Assert
+        Assert.Equal(75, actual, precision: 10);
+        Assert.Equal(-250, representation.DataType.DecodeDouble(bytes, BitConverter.IsLittleEndian ? ByteOrder.LittleEndian : ByteOrder.BigEndian));
+    }
+
+    [Fact]
+    public void CanDeduplicateIdenticalRepresentations()
+    {
+        // Arrange
+        var representations = new Representation[]
+        {
+            new(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1)),
+            new(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1)),
+            new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1)).WithDescription("minute"),
+            new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1)).WithDescription("minute")
+        };
+
+        // Act
+        var actual = representations.DeduplicateIdentical();
+
+        // Assert
+        Assert.Equal(new string[] { "1_s", "1_min" }, actual.Select(representation => representation.Id));
+        Assert.Equal("minute", actual[1].Properties!.GetStringValue(DataModelExtensions.DescriptionKey));
+    }
+
+    [Theory]
+    [InlineData("other")]
+    [InlineData(default)]
+    public void DeduplicateIdenticalThrowsForPropertyConflictOrNonConsecutiveDuplicate(string? description)
+    {
+        // Arrange
+        var representation = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1));
+
+        var representations = new Representation[]
+        {
+            representation,
+            description is null
+                ? new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1))
+                : representation.WithDescription(description),
+            representation
+        };
+
+        // Act
+        void action() => representations.DeduplicateIdentical();
+
+        // Assert
+        Assert.Throws<Exception>(action);
+    }
+
+    [Fact]
+    public void DeduplicateIdenticalThrowsForConflict()
+    {
+        // Arrange
+        var representations = new Representation[]
+        {
+            new(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1)),
+            new(NexusDataType.INT16, TimeSpan.FromSeconds(1))
+        };
+
+        // Act
+        void action() => representations.DeduplicateIdentical();
+
+        // Assert
+        Assert.Throws<Exception>(action);
+    }
+
+    [Fact]
+    public void CatalogIsReadOnlyByDefault()
+    {
+        var catalog = new ResourceCatalogBuilder(id: "/A/B/C")
+            .Build();
+
+        Assert.False(catalog.IsWritable());
+    }
+
+    [Fact]
+    public void CanMarkCatalogAsWritable()
+    {
+        var catalog = new ResourceCatalogBuilder(id: "/A/B/C")
+            .WithWritable(true)
+            .Build();
+
+        Assert.True(catalog.IsWritable());
+    }
+
+    [Fact]
+    public void CanSetSensorMetadata()
+    {
+        var expected = new SensorMetadata(
+            Serial: "SN-1234",
+            CalibratedAt: new DateTime(2024, 03, 01, 12, 0, 0, DateTimeKind.Utc),
+            Location: "Tower 2");
+
+        var resource = new ResourceBuilder(id: "Resource1")
+            .WithSensorMetadata(expected)
+            .Build();
+
+        var actual = resource.GetSensorMetadata();
+
+        Assert.Equal(expected, actual);
+        Assert.Equal(DateTimeKind.Utc, actual!.CalibratedAt!.Value.Kind);
+        Assert.Null(new Resource(id: "Resource2").GetSensorMetadata());
+    }
+
+    [Fact]
+    public void CanSetUnitAndUnitName()
+    {
+        var resource = new ResourceBuilder(id: "Resource1")
+            .WithUnit("°C")
+            .WithUnitName("degrees Celsius")
+            .Build();
+
+        Assert.Equal("°C", resource.Properties?.GetStringValue(DataModelExtensions.UnitKey));
+        Assert.Equal("degrees Celsius", resource.GetUnitName());
+        Assert.Null(new Resource(id: "Resource2").GetUnitName());
+    }
+
+    [Fact]
+    public void CanGetDefaultRepresentation()
+    {
+        var representation1 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1));
+        var representation2 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1));
+
+        var resource = new ResourceBuilder(id: "Resource1")
+            .AddRepresentations(representation1, representation2)
+            .WithDefaultRepresentation(representation2)
+            .Build();
+
+        Assert.Equal(representation2, resource.GetDefaultRepresentation());
+    }
+
+    [Fact]
+    public void BuildThrowsForDanglingDefaultRepresentation()
+    {
+        var representation1 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1));
+        var representation2 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1));
+
+        var builder = new ResourceBuilder(id: "Resource1")
+            .AddRepresentation(representation1)
+            .WithDefaultRepresentation(representation2);
+
+        Assert.Throws<Exception>(() => builder.Build());
+    }
+
+    [Fact]
+    public void GetDefaultRepresentationThrowsForDanglingReference()
+    {
+        var representation = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1));
+
+        var properties = new Dictionary<string, JsonElement>()
+        {
+            [DataModelExtensions.DefaultRepresentationKey] = JsonSerializer.SerializeToElement("1_min")
+        };
+
+        var resource = new Resource(id: "Resource1", properties: properties, representations: [representation]);
+
+        Assert.Throws<Exception>(() => resource.GetDefaultRepresentation());
+    }
+
+    [Fact]
+    public void StrictBuilderThrowsForInvalidGroups()
+    {
+        // Arrange
+        var strictBuilder = new ResourceBuilder(id: "Resource1") { IsStrict = true };
+        var builder = new ResourceBuilder(id: "Resource1");
+
+        // Act
+        void action() => strictBuilder.WithGroups("a\nb");
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+        builder.WithGroups("a\nb");
+    }
+
+    [Fact]
+    public void StrictBuilderRejectsDuplicateProperty()
+    {
+        // Arrange
+        var strictBuilder = new ResourceBuilder(id: "Resource1") { IsStrict = true };
+        var builder = new ResourceBuilder(id: "Resource1");
+
+        // Act
+        strictBuilder.WithUnit("m/s").WithUnit("km/h").WithDescription("");
+        builder.WithUnit("m/s").WithUnit("km/h").WithDescription("");
+
+        // Assert
+        var exception = Assert.Throws<AggregateException>(() => strictBuilder.Build());
+        Assert.Equal(2, exception.InnerExceptions.Count);
+        Assert.Equal("km/h", builder.Build().Properties?.GetStringValue(DataModelExtensions.UnitKey));
+    }
+
+    [Fact]
+    public void StrictCatalogBuilderRejectsDuplicateProperty()
+    {
+        // Arrange
+        var strictBuilder = new ResourceCatalogBuilder(id: "/A/B/C") { IsStrict = true };
+        var builder = new ResourceCatalogBuilder(id: "/A/B/C");
+
+        // Act
+        strictBuilder.WithReadme("a").WithReadme("b");
+        builder.WithReadme("a").WithReadme("b");
+
+        // Assert
+        Assert.Throws<AggregateException>(() => strictBuilder.Build());
+        Assert.Equal("b", builder.Build().Properties?.GetStringValue(DataModelExtensions.ReadmeKey));
+    }
+
     [Theory]
     [InlineData("A and B/C/D", UriKind.Relative, "A and B/C/D")]
     [InlineData("A and B/C/D.ext", UriKind.Relative, "A and B/C/D.ext")]
@@ -3,6 +3,7 @@
 
 using Nexus.DataModel;
 using System.Text.Json;
+using System.Text.Json.Serialization;
 using Xunit;
 
 namespace Nexus.Extensibility.Tests;
@@ -35,6 +36,82 @@ public class DataModelTests(DataModelFixture fixture)
             Assert.Throws<ArgumentException>(() => new ResourceCatalog(id: id));
     }
 
+    [Fact]
+    public void RootIsPathOnly()
+    {
+        _ = new CatalogRegistration(ResourceCatalog.RootPath, Title: default);
+        Assert.Throws<ArgumentException>(() => new ResourceCatalog(id: ResourceCatalog.RootPath));
+    }
+
+    [Theory]
+    [InlineData("/a/b/c", true)]
+    [InlineData("/a/b/c/d", false)]
+    [InlineData("/abc/def", false)]
+    public void CanValidateCatalogIdLimits(string catalogId, bool isValid)
+    {
+        void action() => ResourceCatalog.ValidateIdLimits(catalogId, maxDepth: 3, maxLength: 6);
+
+        if (isValid)
+            action();
+
+        else
+            Assert.Throws<ArgumentException>(action);
+    }
+
+    [Fact]
+    public void CatalogRegistrationThrowsForSelfLink()
+    {
+        _ = new CatalogRegistration("/A/B", Title: default, LinkTarget: "/A/C");
+
+        var exception = Assert.Throws<ArgumentException>(() => new CatalogRegistration("/A/B", Title: default, LinkTarget: "/A/B"));
+        Assert.Contains("itself", exception.Message);
+    }
+
+    [Theory]
+    [InlineData("/a", "b", "/a/b")]
+    [InlineData("/", "b", "/b")]
+    [InlineData("/a", "b/c", default)]
+    [InlineData("/a", "9b", default)]
+    public void CanJoinCatalogId(string catalogId, string segment, string? expected)
+    {
+        if (expected is null)
+        {
+            Assert.Throws<ArgumentException>(() => ResourceCatalog.JoinId(catalogId, segment));
+        }
+
+        else
+        {
+            var actual = ResourceCatalog.JoinId(catalogId, segment);
+            Assert.Equal(expected, actual);
+        }
+    }
+
+    [Fact]
+    public void CanDeclareChildCatalogs()
+    {
+        // Act
+        var catalog = new ResourceCatalogBuilder(id: "/A/B")
+            .AddChildId("/A/B/C")
+            .AddChildId("/A/B/D")
+            .Build();
+
+        // Assert
+        Assert.Equal(new[] { "/A/B/C", "/A/B/D" }, catalog.ChildIds);
+    }
+
+    [Theory]
+    [InlineData("/A/X")]
+    [InlineData("/A/B")]
+    [InlineData("A/B/C")]
+    public void CatalogConstructorThrowsForInvalidChildId(string childId)
+    {
+        // Act
+        void action() => new ResourceCatalog(id: "/A/B", childIds: [childId]);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+    }
+
     [Theory]
 
     // valid
@@ -60,9 +137,43 @@ public class DataModelTests(DataModelFixture fixture)
             Assert.Throws<ArgumentException>(() => new Resource(id: id));
     }
 
+    [Theory]
+    [InlineData("voltage * current", true)]
+    [InlineData("(voltage - 1.5) / 2", true)]
+    [InlineData("", false)]
+    [InlineData("2 * 3", false)]
+    [InlineData("voltage *", false)]
+    [InlineData("power / 2", false)]
+    [InlineData("voltage * ström", false)]
+    public void CanValidateVirtualResourceExpression(string expression, bool isValid)
+    {
+        var resource = new Resource(id: "power");
+
+        if (isValid)
+            _ = new VirtualResource(resource, expression);
+
+        else
+            Assert.Throws<ArgumentException>(() => new VirtualResource(resource, expression));
+    }
+
+    [Fact]
+    public void CanGetVirtualResourceInputIds()
+    {
+        // Arrange
+        var virtualResource = new VirtualResource(new Resource(id: "power"), "voltage * current + voltage");
+
+        // Act
+        var actual = virtualResource.InputIds;
+
+        // Assert
+        Assert.Equal(new[] { "voltage", "current" }, actual);
+    }
+
     [Theory]
     [InlineData("00:01:00", true)]
     [InlineData("00:00:00", false)]
+    [InlineData("-00:00:01", false)]
+    [InlineData("109500.00:00:00", false)]
     public void CanValidateRepresentationSamplePeriod(string samplePeriodString, bool isValid)
     {
         var samplePeriod = TimeSpan.Parse(samplePeriodString);
@@ -117,6 +228,25 @@ public class DataModelTests(DataModelFixture fixture)
                  samplePeriod: TimeSpan.FromSeconds(1)));
     }
 
+    [Theory]
+    [InlineData(NexusDataType.INT32, "Mean", true)]
+    [InlineData(NexusDataType.FLOAT32, "Mean", false)]
+    [InlineData(NexusDataType.UINT16, "MaxBitwise", false)]
+    public void CanCheckAggregationType(NexusDataType dataType, string kindString, bool expectWarning)
+    {
+        var kind = Enum.Parse<RepresentationKind>(kindString);
+
+        var representation = new Representation(
+            dataType: dataType,
+            samplePeriod: TimeSpan.FromMinutes(10),
+            parameters: default,
+            kind: kind);
+
+        var warning = representation.CheckAggregationType();
+
+        Assert.Equal(expectWarning, warning is not null);
+    }
+
     [Theory]
     [InlineData("00:00:01", "MeanPolarDeg", "1_s_mean_polar_deg")]
     public void CanInferRepresentationId(string samplePeriodString, string kindString, string expected)
@@ -135,6 +265,211 @@ public class DataModelTests(DataModelFixture fixture)
         Assert.Equal(expected, actual);
     }
 
+    [Fact]
+    public void CanAddRepresentationProperties()
+    {
+        // Arrange
+        var representation = new Representation(
+            dataType: NexusDataType.FLOAT64,
+            samplePeriod: TimeSpan.FromSeconds(1));
+
+        // Act
+        var actual = representation
+            .WithDescription("rms computed over 10 samples")
+            .WithProperty("window", 10);
+
+        // Assert
+        Assert.Null(representation.Properties);
+        Assert.NotNull(actual.Properties);
+        Assert.Equal("rms computed over 10 samples", actual.Properties.GetStringValue(DataModelExtensions.DescriptionKey));
+        Assert.Equal(10, actual.Properties["window"].GetInt32());
+    }
+
+    [Theory]
+    [InlineData("00:00:01", "Original")]
+    [InlineData("00:00:00.1", "Original")]
+    [InlineData("00:10:00", "Mean")]
+    [InlineData("00:10:00", "MeanPolarDeg")]
+    [InlineData("01:00:00", "MaxBitwise")]
+    public void CanComputeRepresentationIdWithoutConstruction(string samplePeriodString, string kindString)
+    {
+        var kind = Enum.Parse<RepresentationKind>(kindString);
+        var samplePeriod = TimeSpan.Parse(samplePeriodString);
+
+        var expected = new Representation(
+            dataType: NexusDataType.FLOAT64,
+            samplePeriod: samplePeriod,
+            parameters: default,
+            kind: kind).Id;
+
+        var actual = Representation.GetId(samplePeriod, kind);
+
+        Assert.Equal(expected, actual);
+
+        if (kind == RepresentationKind.Original)
+            Assert.Equal(expected, Representation.GetId(samplePeriod));
+    }
+
+    [Fact]
+    public void RepresentationsWithSamePeriodButDifferentKindsAreDistinct()
+    {
+        // Arrange
+        var samplePeriod = TimeSpan.FromMilliseconds(10);
+        var original = new Representation(NexusDataType.FLOAT64, samplePeriod, parameters: default, kind: RepresentationKind.Original);
+        var mean = new Representation(NexusDataType.FLOAT64, samplePeriod, parameters: default, kind: RepresentationKind.Mean);
+
+        // Act
+        var resource = new Resource(id: "Resource1", representations: [original, mean]);
+
+        // Assert
+        Assert.Equal("10_ms", original.Id);
+        Assert.Equal("10_ms_mean", mean.Id);
+        Assert.Equal(RepresentationKind.Mean, mean.Kind);
+        Assert.Equal(2, resource.Representations!.Count);
+    }
+
+    [Fact]
+    public void RepresentationKindSurvivesSerialization()
+    {
+        // Arrange
+        var expected = new Representation(NexusDataType.FLOAT64, TimeSpan.FromMinutes(10), default, RepresentationKind.Mean);
+
+        // Act
+        var json = JsonSerializer.Serialize(expected);
+        var actual = JsonSerializer.Deserialize<Representation>(json)!;
+
+        // Assert
+        Assert.Equal("10_min_mean", actual.Id);
+        Assert.Equal(RepresentationKind.Mean, actual.Kind);
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void CanRoundTripCatalogThroughJson()
+    {
+        // Arrange
+        var options = new JsonSerializerOptions();
+        options.Converters.Add(new JsonStringEnumConverter());
+
+        var representation1 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromMilliseconds(10));
+        var representation2 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromMilliseconds(10), default, RepresentationKind.Mean);
+
+        var resource = new ResourceBuilder(id: "Resource1")
+            .WithUnit("m/s")
+            .AddRepresentation(representation1)
+            .AddRepresentation(representation2)
+            .Build();
+
+        var expected = new ResourceCatalogBuilder(id: "/A/B/C")
+            .AddResource(resource)
+            .Build();
+
+        // Act
+        var json = JsonSerializer.Serialize(expected, options);
+        var actual = JsonSerializer.Deserialize<ResourceCatalog>(json, options)!;
+
+        // Assert
+        Assert.Contains("\"FLOAT64\"", json);
+        Assert.Contains("\"Mean\"", json);
+        Assert.Equal(json, JsonSerializer.Serialize(actual, options));
+        Assert.Equal("10_ms", actual.Resources![0].Representations![0].Id);
+        Assert.Equal("10_ms_mean", actual.Resources![0].Representations![1].Id);
+        Assert.Equal(RepresentationKind.Mean, actual.Resources![0].Representations![1].Kind);
+    }
+
+    [Theory]
+    [InlineData("{ \"Id\": \"A/B/C\" }")]
+    [InlineData("{ \"Id\": \"/A/B/C\", \"Resources\": [ { \"Id\": \"1Resource\" } ] }")]
+    [InlineData("{ \"Id\": \"/A/B/C\", \"Resources\": [ { \"Id\": \"Resource1\", \"Representations\": [ { \"DataType\": 832, \"SamplePeriod\": \"-00:00:01\" } ] } ] }")]
+    public void DeserializationValidatesCatalog(string json)
+    {
+        // Act
+        void action() => JsonSerializer.Deserialize<ResourceCatalog>(json);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+    }
+
+    [Fact]
+    public void CanCheckRepresentationCompatibility()
+    {
+        // Arrange
+        var samplePeriod = TimeSpan.FromSeconds(1);
+
+        var parameters = new Dictionary<string, JsonElement>()
+        {
+            ["offset"] = JsonSerializer.SerializeToElement(new { type = "input-integer" })
+        };
+
+        var representation = new Representation(NexusDataType.FLOAT64, samplePeriod);
+        var identical = new Representation(NexusDataType.FLOAT64, samplePeriod);
+        var otherId = new Representation(NexusDataType.INT32, TimeSpan.FromMinutes(1));
+        var otherDataType = new Representation(NexusDataType.INT32, samplePeriod);
+        var otherParameters = new Representation(NexusDataType.FLOAT64, samplePeriod, parameters);
+
+        // Act / Assert
+        Assert.True(representation.IsCompatibleWith(identical));
+        Assert.True(representation.IsCompatibleWith(otherId));
+        Assert.False(representation.IsCompatibleWith(otherDataType));
+        Assert.False(representation.IsCompatibleWith(otherParameters));
+    }
+
+    [Fact]
+    public void RepresentationIdIgnoresParameters()
+    {
+        // Arrange
+        var parameters = new Dictionary<string, JsonElement>()
+        {
+            ["window"] = JsonSerializer.SerializeToElement(new { type = "input-integer" })
+        };
+
+        var representation = new Representation(
+            dataType: NexusDataType.FLOAT64,
+            samplePeriod: TimeSpan.FromMinutes(10),
+            parameters: parameters,
+            kind: RepresentationKind.Mean);
+
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+        var catalog = new ResourceCatalog(id: "/A/B/C", resources: [resource]);
+
+        var catalogItem1 = new CatalogItem(catalog, resource, representation, new Dictionary<string, string>() { ["window"] = "10" });
+        var catalogItem2 = new CatalogItem(catalog, resource, representation, new Dictionary<string, string>() { ["window"] = "20" });
+
+        // Assert
+        Assert.Equal("10_min_mean", catalogItem1.Representation.Id);
+        Assert.Equal(catalogItem1.Representation.Id, catalogItem2.Representation.Id);
+        Assert.NotEqual(catalogItem1.ToPath(), catalogItem2.ToPath());
+    }
+
+    [Theory]
+    [InlineData(null, "/A/B/C/Resource1/10_min_mean")]
+    [InlineData("window=10", "/A/B/C/Resource1/10_min_mean(window=10)")]
+    [InlineData("window=10,offset=2,alpha=x", "/A/B/C/Resource1/10_min_mean(alpha=x,offset=2,window=10)")]
+    public void CanConvertCatalogItemToPath(string? parametersString, string expected)
+    {
+        // Arrange
+        var parameters = parametersString?
+            .Split(',')
+            .Select(parameter => parameter.Split('='))
+            .ToDictionary(parameter => parameter[0], parameter => parameter[1]);
+
+        var representation = new Representation(
+            dataType: NexusDataType.FLOAT64,
+            samplePeriod: TimeSpan.FromMinutes(10),
+            parameters: default,
+            kind: RepresentationKind.Mean);
+
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+        var catalog = new ResourceCatalog(id: "/A/B/C", resources: [resource]);
+        var catalogItem = new CatalogItem(catalog, resource, representation, parameters);
+
+        // Act
+        var actual = catalogItem.ToPath();
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
     [Fact]
     public void CanMergeCatalogs()
     {
@@ -236,6 +571,465 @@ public class DataModelTests(DataModelFixture fixture)
         Assert.Throws<Exception>(action);
     }
 
+    [Fact]
+    public void CanMergeResourcesWithEqualRepresentationProperties()
+    {
+        // Arrange
+        var properties = new Dictionary<string, JsonElement>()
+        {
+            ["description"] = JsonSerializer.SerializeToElement("rms computed over 10 samples")
+        };
+
+        var resource1 = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                new(dataType: NexusDataType.FLOAT32, samplePeriod: TimeSpan.FromSeconds(1), properties: properties)
+            });
+
+        var resource2 = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                new(dataType: NexusDataType.FLOAT32, samplePeriod: TimeSpan.FromSeconds(1), properties: properties.ToDictionary(entry => entry.Key, entry => entry.Value.Clone()))
+            });
+
+        // Act
+        var actual1 = resource1.Merge(resource2);
+        var actual2 = resource1.Merge(resource1);
+
+        // Assert
+        var representation = Assert.Single(actual1.Representations!);
+
+        Assert.Equal("rms computed over 10 samples", representation.Properties!.GetStringValue("description"));
+        Assert.Single(actual2.Representations!);
+    }
+
+    [Fact]
+    public void ResourceMergeReportsRepresentationIdCollisions()
+    {
+        // Arrange
+        var resource1 = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                new(dataType: NexusDataType.FLOAT32, samplePeriod: TimeSpan.FromSeconds(1))
+            });
+
+        var resource2 = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                new(dataType: NexusDataType.INT16, samplePeriod: TimeSpan.FromSeconds(1))
+            });
+
+        // Act
+        void action() => resource1.Merge(resource2);
+
+        // Assert
+        var exception = Assert.Throws<Exception>(action);
+
+        Assert.Contains("1_s", exception.Message);
+        Assert.Contains("myresource", exception.Message);
+        Assert.Contains("both", exception.Message);
+    }
+
+    [Fact]
+    public void CanOrderRepresentationsForDisplay()
+    {
+        // Arrange
+        static Representation Create(TimeSpan samplePeriod, RepresentationKind kind)
+            => new(NexusDataType.FLOAT64, samplePeriod, parameters: default, kind: kind);
+
+        var resource = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                Create(TimeSpan.FromMinutes(10), RepresentationKind.Max),
+                Create(TimeSpan.FromMinutes(1), RepresentationKind.Mean),
+                Create(TimeSpan.FromSeconds(1), RepresentationKind.Original),
+                Create(TimeSpan.FromMinutes(10), RepresentationKind.Mean),
+                Create(TimeSpan.FromMilliseconds(100), RepresentationKind.Original)
+            });
+
+        // Act
+        var actual = resource
+            .GetRepresentationsForDisplay()
+            .Select(representation => representation.Id);
+
+        // Assert
+        var expected = new[] { "100_ms", "1_s", "1_min_mean", "10_min_mean", "10_min_max" };
+        Assert.Equal(expected, actual);
+    }
+
+    [Theory]
+    [InlineData("00:00:01", 86400L)]
+    [InlineData("00:01:00", 1440L)]
+    [InlineData("00:00:07", -1L)]
+    [InlineData("2.00:00:00", -1L)]
+    public void CanGetElementsPerDay(string samplePeriodString, long expected)
+    {
+        var representation = new Representation(NexusDataType.FLOAT64, TimeSpan.Parse(samplePeriodString));
+
+        if (expected == -1)
+            Assert.Throws<Exception>(() => representation.GetElementsPerDay());
+
+        else
+            Assert.Equal(expected, representation.GetElementsPerDay());
+    }
+
+    [Fact]
+    public void CanGetRepresentationOptions()
+    {
+        // Arrange
+        var resource = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1), parameters: default, kind: RepresentationKind.MeanPolarDeg),
+                new(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1), parameters: default, kind: RepresentationKind.Original),
+                new(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1), parameters: default, kind: RepresentationKind.Mean)
+            });
+
+        // Act
+        var actual = resource.GetRepresentationOptions();
+
+        // Assert
+        var expected = new (string, string)[]
+        {
+            ("1_s", "1 s"),
+            ("1_min_mean", "1 min (mean)"),
+            ("1_min_mean_polar_deg", "1 min (mean polar deg)")
+        };
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void CanValidatePeriodHierarchy()
+    {
+        // Arrange
+        static Representation Create(TimeSpan samplePeriod, RepresentationKind kind)
+            => new(NexusDataType.FLOAT64, samplePeriod, parameters: default, kind: kind);
+
+        var resource = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                Create(TimeSpan.FromSeconds(1), RepresentationKind.Original),
+                Create(TimeSpan.FromMinutes(1), RepresentationKind.Mean),
+                Create(TimeSpan.FromMinutes(10), RepresentationKind.Max)
+            });
+
+        // Act
+        resource.ValidatePeriodHierarchy();
+    }
+
+    [Fact]
+    public void ValidatePeriodHierarchyThrowsForNonMultiple()
+    {
+        // Arrange
+        static Representation Create(TimeSpan samplePeriod, RepresentationKind kind)
+            => new(NexusDataType.FLOAT64, samplePeriod, parameters: default, kind: kind);
+
+        var resource = new Resource(
+            id: "myresource",
+            representations: new List<Representation>()
+            {
+                Create(TimeSpan.FromSeconds(2), RepresentationKind.Original),
+                Create(TimeSpan.FromSeconds(3), RepresentationKind.Mean)
+            });
+
+        // Act
+        void action() => resource.ValidatePeriodHierarchy();
+
+        // Assert
+        var exception = Assert.Throws<Exception>(action);
+        Assert.Contains("3_s_mean", exception.Message);
+    }
+
+    [Theory]
+    [InlineData("m/s", true)]
+    [InlineData(null, true)]
+    [InlineData("km/h", false)]
+    public void CanValidateUnitConsistency(string? representationUnit, bool isValid)
+    {
+        // Arrange
+        var representationProperties = representationUnit is null
+            ? default
+            : new Dictionary<string, JsonElement>()
+            {
+                [DataModelExtensions.UnitKey] = JsonSerializer.SerializeToElement(representationUnit)
+            };
+
+        var resource = new ResourceBuilder(id: "myresource")
+            .WithUnit("m/s")
+            .AddRepresentation(new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1), properties: representationProperties))
+            .Build();
+
+        // Act
+        void action() => resource.ValidateUnitConsistency();
+
+        // Assert
+        if (isValid)
+            action();
+
+        else
+            Assert.Throws<Exception>(action);
+    }
+
+    [Fact]
+    public void CanMigrateCatalogProperties()
+    {
+        // Arrange
+        var catalog = new ResourceCatalogBuilder(id: "/A/B/C")
+            .WithReadme("# Readme")
+            .Build();
+
+        // Act
+        var actual = catalog.MigrateProperties(targetVersion: ResourceCatalog.CurrentSchemaVersion);
+
+        // Assert
+        Assert.Null(catalog.GetSchemaVersion());
+        Assert.Equal(ResourceCatalog.CurrentSchemaVersion, actual.GetSchemaVersion());
+        Assert.Equal("# Readme", actual.Properties!.GetStringValue(DataModelExtensions.ReadmeKey));
+    }
+
+    [Fact]
+    public void CanRenamePropertiesDuringMigration()
+    {
+        // Arrange
+        var properties = new Dictionary<string, JsonElement>()
+        {
+            ["old-key"] = JsonSerializer.SerializeToElement("value")
+        };
+
+        var schemaRenames = new Dictionary<int, (string From, string To)[]>()
+        {
+            [1] = [("old-key", "new-key")]
+        };
+
+        // Act
+        var actual = ResourceCatalog.MigrateProperties(properties, version: 1, targetVersion: 2, schemaRenames);
+
+        // Assert
+        Assert.False(actual.ContainsKey("old-key"));
+        Assert.Equal("value", actual.GetStringValue("new-key"));
+        Assert.Equal(2, actual[DataModelExtensions.SchemaVersionKey].GetInt32());
+    }
+
+    [Fact]
+    public void MigrateCatalogPropertiesThrowsForRenameConflict()
+    {
+        // Arrange
+        var properties = new Dictionary<string, JsonElement>()
+        {
+            ["old-key"] = JsonSerializer.SerializeToElement("old value"),
+            ["new-key"] = JsonSerializer.SerializeToElement("new value")
+        };
+
+        var schemaRenames = new Dictionary<int, (string From, string To)[]>()
+        {
+            [1] = [("old-key", "new-key")]
+        };
+
+        // Act
+        void action() => ResourceCatalog.MigrateProperties(properties, version: 1, targetVersion: 2, schemaRenames);
+
+        // Assert
+        Assert.Throws<Exception>(action);
+    }
+
+    [Fact]
+    public void MigrateCatalogPropertiesThrowsForDowngrade()
+    {
+        // Arrange
+        var catalog = new ResourceCatalogBuilder(id: "/A/B/C")
+            .WithProperty(DataModelExtensions.SchemaVersionKey, 2)
+            .Build();
+
+        // Act
+        void action() => catalog.MigrateProperties(targetVersion: 1);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+    }
+
+    [Fact]
+    public void CanGetDistinctKinds()
+    {
+        // Arrange
+        static Representation Create(TimeSpan samplePeriod, RepresentationKind kind)
+            => new(NexusDataType.FLOAT64, samplePeriod, parameters: default, kind: kind);
+
+        var catalog = new ResourceCatalog(
+            id: "/A/B/C",
+            resources:
+            [
+                new Resource(id: "R1", representations:
+                [
+                    Create(TimeSpan.FromSeconds(1), RepresentationKind.Original),
+                    Create(TimeSpan.FromMinutes(1), RepresentationKind.Max)
+                ]),
+                new Resource(id: "R2", representations:
+                [
+                    Create(TimeSpan.FromSeconds(1), RepresentationKind.Original),
+                    Create(TimeSpan.FromMinutes(1), RepresentationKind.Mean)
+                ]),
+                new Resource(id: "R3")
+            ]);
+
+        // Act
+        var actual = catalog.GetDistinctKinds();
+
+        // Assert
+        Assert.Equal(new RepresentationKind[] { RepresentationKind.Original, RepresentationKind.Mean, RepresentationKind.Max }, actual);
+    }
+
+    [Fact]
+    public void CanGetRepresentationIdIndex()
+    {
+        // Arrange
+        var representation1 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1));
+        var representation2 = new Representation(NexusDataType.INT16, TimeSpan.FromSeconds(1));
+        var representation3 = new Representation(NexusDataType.FLOAT64, TimeSpan.FromMinutes(1));
+
+        var catalog = new ResourceCatalog(
+            id: "/A/B/C",
+            resources:
+            [
+                new Resource(id: "R1", representations: [representation1, representation3]),
+                new Resource(id: "R2", representations: [representation2])
+            ]);
+
+        // Act
+        var actual = catalog.GetRepresentationIdIndex();
+
+        // Assert
+        Assert.Equal(new string[] { "R1", "R2" }, actual["1_s"]);
+        Assert.Equal(new string[] { "R1" }, actual["1_min"]);
+    }
+
+    [Fact]
+    public void CanGetUsedParameters()
+    {
+        // Arrange
+        static Representation create(TimeSpan samplePeriod, string windowDefinition)
+            => new(
+                NexusDataType.FLOAT64,
+                samplePeriod,
+                parameters: new Dictionary<string, JsonElement>()
+                {
+                    ["window"] = JsonSerializer.Deserialize<JsonElement>(windowDefinition)
+                });
+
+        var catalog = new ResourceCatalog(
+            id: "/A/B/C",
+            resources:
+            [
+                new Resource(id: "R1", representations: [create(TimeSpan.FromSeconds(1), @"{""type"":""input-integer""}")]),
+                new Resource(id: "R2", representations: [create(TimeSpan.FromSeconds(1), @"{""type"":""select""}")]),
+                new Resource(id: "R3", representations: [create(TimeSpan.FromMinutes(1), @"{""type"":""select""}")])
+            ]);
+
+        // Act
+        var actual = catalog.GetUsedParameters();
+
+        // Assert
+        var definitions = Assert.Single(actual).Value;
+
+        Assert.Equal(
+            new string?[] { "input-integer", "select" },
+            definitions.Select(definition => definition.GetStringValue("type")));
+    }
+
+    [Fact]
+    public void CanCountCatalogsAndResourcesInTree()
+    {
+        // Arrange
+        var catalogs = new ResourceCatalog[]
+        {
+            new ResourceCatalogBuilder(id: "/A")
+                .AddResource(new Resource(id: "R1"))
+                .AddChildIds("/A/B", "/A/C")
+                .Build(),
+
+            new ResourceCatalogBuilder(id: "/A/B")
+                .AddResources(new Resource(id: "R1"), new Resource(id: "R2"))
+                .AddChildId("/A/B/C")
+                .Build(),
+
+            new ResourceCatalogBuilder(id: "/A/C")
+                .Build(),
+
+            new ResourceCatalogBuilder(id: "/A/B/C")
+                .AddResources(new Resource(id: "R1"), new Resource(id: "R2"), new Resource(id: "R3"))
+                .Build()
+        }.ToDictionary(catalog => catalog.Id);
+
+        var root = catalogs["/A"];
+
+        // Act
+        var catalogCount = root.GetCatalogCount(catalogs);
+        var resourceCount = root.GetResourceCount(catalogs);
+
+        // Assert
+        Assert.Equal(4, catalogCount);
+        Assert.Equal(6, resourceCount);
+    }
+
+    [Fact]
+    public void CanCloneBuilderTemplate()
+    {
+        // Arrange
+        var template = new ResourceCatalogBuilder(id: "/A/B/C")
+            .WithReadme("template")
+            .AddResource(new Resource(id: "R1"));
+
+        // Act
+        var builder1 = (template with { })
+            .WithLicense("license1")
+            .AddResource(new Resource(id: "R2"));
+
+        var builder2 = (template with { })
+            .WithLicense("license2");
+
+        var catalog1 = builder1.Build();
+        var catalog2 = builder2.Build();
+        var catalog3 = template.Build();
+
+        // Assert
+        Assert.Equal("license1", catalog1.Properties?.GetStringValue(DataModelExtensions.LicenseKey));
+        Assert.Equal("license2", catalog2.Properties?.GetStringValue(DataModelExtensions.LicenseKey));
+        Assert.Null(catalog3.Properties?.GetStringValue(DataModelExtensions.LicenseKey));
+
+        Assert.Equal(2, catalog1.Resources!.Count);
+        Assert.Single(catalog2.Resources!);
+        Assert.Single(catalog3.Resources!);
+    }
+
+    [Fact]
+    public void TryAddResourcesReportsFirstDuplicate()
+    {
+        // Arrange
+        var builder = new ResourceCatalogBuilder(id: "/A/B/C")
+            .AddResource(new Resource(id: "R1"));
+
+        // Act
+        var success1 = builder.TryAddResources([new Resource(id: "R2"), new Resource(id: "R3")], out var duplicateId1);
+        var success2 = builder.TryAddResources([new Resource(id: "R4"), new Resource(id: "R2"), new Resource(id: "R4")], out var duplicateId2);
+
+        // Assert
+        Assert.True(success1);
+        Assert.Null(duplicateId1);
+
+        Assert.False(success2);
+        Assert.Equal("R2", duplicateId2);
+
+        var catalog = builder.Build();
+        Assert.Equal(new string[] { "R1", "R2", "R3" }, catalog.Resources!.Select(resource => resource.Id));
+    }
+
     [Fact]
     public void ResourceMergeThrowsForNonMatchingIdentifiers()
     {
@@ -271,6 +1065,111 @@ public class DataModelTests(DataModelFixture fixture)
         Assert.Equal(catalogItem, foundCatalogItem);
     }
 
+    [Fact]
+    public void CatalogItemsWithEqualKeysAreEqual()
+    {
+        static CatalogItem createCatalogItem(string description)
+        {
+            var representation = new Representation(NexusDataType.FLOAT32, TimeSpan.FromSeconds(1));
+
+            var resource = new ResourceBuilder(id: "Resource1")
+                .WithDescription(description)
+                .AddRepresentation(representation)
+                .Build();
+
+            var catalog = new ResourceCatalog(id: "/A/B/C", resources: [resource]);
+
+            return new CatalogItem(catalog, resource, representation, new Dictionary<string, string>() { ["a"] = "1", ["b"] = "2" });
+        }
+
+        var catalogItem1 = createCatalogItem("foo");
+        var catalogItem2 = createCatalogItem("bar") with { Parameters = new Dictionary<string, string>() { ["b"] = "2", ["a"] = "1" } };
+        var catalogItem3 = catalogItem2 with { Parameters = new Dictionary<string, string>() { ["a"] = "2", ["b"] = "2" } };
+
+        Assert.Equal(catalogItem1, catalogItem2);
+        Assert.Equal(catalogItem1.GetHashCode(), catalogItem2.GetHashCode());
+        Assert.NotEqual(catalogItem1, catalogItem3);
+
+        var cache = new Dictionary<CatalogItem, int>() { [catalogItem1] = 1 };
+        Assert.True(cache.ContainsKey(catalogItem2));
+    }
+
+    [Fact]
+    public void CatalogItemParameterOrderingIsNormalized()
+    {
+        var representation = new Representation(NexusDataType.FLOAT32, TimeSpan.FromSeconds(1));
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+        var catalog = new ResourceCatalog(id: "/A/B/C", resources: [resource]);
+
+        var catalogItem1 = new CatalogItem(catalog, resource, representation, new Dictionary<string, string>() { ["a"] = "1", ["b"] = "2" });
+        var catalogItem2 = new CatalogItem(catalog, resource, representation, new Dictionary<string, string>() { ["b"] = "2", ["a"] = "1" });
+
+        Assert.Equal(catalogItem1, catalogItem2);
+        Assert.Equal(catalogItem1.GetHashCode(), catalogItem2.GetHashCode());
+        Assert.Equal("/A/B/C/Resource1/1_s(a=1,b=2)", catalogItem2.ToPath());
+        Assert.Equal(catalogItem1.ToPath(), catalogItem2.ToPath());
+    }
+
+    [Fact]
+    public void CanGetSortedParameters()
+    {
+        var representation = new Representation(NexusDataType.FLOAT32, TimeSpan.FromSeconds(1));
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+        var catalog = new ResourceCatalog(id: "/A/B/C", resources: [resource]);
+
+        var catalogItem1 = new CatalogItem(catalog, resource, representation, new Dictionary<string, string>() { ["b"] = "2", ["a"] = "1", ["c"] = "3" });
+        var catalogItem2 = new CatalogItem(catalog, resource, representation, new Dictionary<string, string>() { ["c"] = "3", ["a"] = "1", ["b"] = "2" });
+
+        var expected = new (string, string)[] { ("a", "1"), ("b", "2"), ("c", "3") };
+
+        Assert.Equal(expected, catalogItem1.GetSortedParameters());
+        Assert.Equal(expected, catalogItem2.GetSortedParameters());
+        Assert.Empty((catalogItem1 with { Parameters = default }).GetSortedParameters());
+    }
+
+    [Fact]
+    public void CanCreateApiPath()
+    {
+        var representation = new Representation(
+            dataType: NexusDataType.FLOAT32,
+            samplePeriod: TimeSpan.FromSeconds(1));
+
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+
+        var catalogItem1 = new CatalogItem(new ResourceCatalog(id: "/foo"), resource, representation, Parameters: default);
+        var catalogItem2 = new CatalogItem(new ResourceCatalog(id: "/A/B"), resource, representation, new Dictionary<string, string>() { ["a"] = "1" });
+
+        Assert.Equal("%2Ffoo/Resource1/1_s", catalogItem1.ToApiPath());
+        Assert.Equal("%2FA%2FB/Resource1/1_s%28a%3D1%29", catalogItem2.ToApiPath());
+    }
+
+    [Fact]
+    public void CanParseApiPath()
+    {
+        var representation = new Representation(
+            dataType: NexusDataType.FLOAT32,
+            samplePeriod: TimeSpan.FromSeconds(1));
+
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+        var catalog = new ResourceCatalog(id: "/foo", resources: [resource]);
+
+        var actual = CatalogItem.ParseApiPath("%2Ffoo/Resource1/1_s", catalog);
+
+        Assert.Equal("/foo/Resource1/1_s", actual.ToPath());
+    }
+
+    [Theory]
+    [InlineData("%2Ffoo%ZZ/Resource1/1_s")]
+    [InlineData("%2Ffoo%2/Resource1/1_s")]
+    [InlineData("%2F9foo/Resource1/1_s")]
+    [InlineData("%2Ffoo")]
+    public void ParseApiPathThrowsForMalformedEncodings(string apiPath)
+    {
+        var catalog = new ResourceCatalog(id: "/foo");
+
+        Assert.Throws<ArgumentException>(() => CatalogItem.ParseApiPath(apiPath, catalog));
+    }
+
     [Fact]
     public void CanTryFindCatalogItem()
     {
@@ -294,6 +1193,63 @@ public class DataModelTests(DataModelFixture fixture)
         Assert.True(success);
     }
 
+    [Fact]
+    public void CanParseResourcePath()
+    {
+        // Act
+        var actual = ResourcePathParseResult.Parse("/A/B/temp/10_ms_mean(foo=bar)#base=1_ms");
+
+        // Assert
+        Assert.Equal("/A/B", actual.CatalogId);
+        Assert.Equal("temp", actual.ResourceId);
+        Assert.Equal(TimeSpan.FromMilliseconds(10), actual.SamplePeriod);
+        Assert.Equal(RepresentationKind.Mean, actual.Kind);
+        Assert.Equal("foo=bar", actual.Parameters);
+        Assert.Equal(TimeSpan.FromMilliseconds(1), actual.BasePeriod);
+    }
+
+    [Fact]
+    public void CanTryParseResourcePath()
+    {
+        // Act
+        var success = ResourcePathParseResult.TryParse("/A/B/temp/1_s", out var actual);
+
+        // Assert
+        Assert.True(success);
+        Assert.Equal(new ResourcePathParseResult("/A/B", "temp", TimeSpan.FromSeconds(1), RepresentationKind.Original, default, default), actual);
+    }
+
+    [Theory]
+    [InlineData("temp")]
+    [InlineData("/A/B/temp/10_xyz")]
+    [InlineData("/A/B/temp/10_s_foo")]
+    [InlineData("/A/B/temp/10_s#base=1_xyz")]
+    public void ParseResourcePathThrowsForMalformedPath(string resourcePath)
+    {
+        // Act
+        void action() => ResourcePathParseResult.Parse(resourcePath);
+
+        // Assert
+        Assert.Throws<FormatException>(action);
+        Assert.False(ResourcePathParseResult.TryParse(resourcePath, out _));
+    }
+
+    [Theory]
+    [InlineData("/a/b/temp", "/a/b", "temp")]
+    [InlineData("/a/temp", "/a", "temp")]
+    [InlineData("/a/b/c/temp", default, default)]
+    [InlineData("/x/temp", default, default)]
+    public void CanSplitCatalogAndResource(string path, string? expectedCatalogId, string? expectedResourceId)
+    {
+        var knownCatalogIds = new[] { "/a", "/a/b" };
+
+        var success = ResourceCatalog.TrySplitCatalogAndResource(path, knownCatalogIds, out var catalogId, out var resourceId);
+
+        Assert.Equal(expectedCatalogId is not null, success);
+        Assert.Equal(expectedCatalogId, catalogId);
+        Assert.Equal(expectedResourceId, resourceId);
+    }
+
     [Theory]
     [InlineData("/A/B/C/Resource1/1_s(param1=2)")]
     [InlineData("/A/B/C/Resource2/1_s")]
@@ -0,0 +1,913 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using Nexus.DataModel;
+using System.Runtime.InteropServices;
+using System.Text.Json;
+using Xunit;
+
+namespace Nexus.Extensibility.Tests;
+
+public class DataSourceTests
+{
+    [Fact]
+    public async Task CanStreamCatalogRegistrations()
+    {
+        // Arrange
+        var dataSource = new MyDataSource();
+
+        // Act
+        var expected = await dataSource.GetCatalogRegistrationsAsync("/", CancellationToken.None);
+        var actual = new List<CatalogRegistration>();
+
+        await foreach (var catalogRegistration in dataSource.StreamCatalogRegistrationsAsync("/", CancellationToken.None))
+        {
+            actual.Add(catalogRegistration);
+        }
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public async Task CanGetLastModified()
+    {
+        // Arrange
+        var expected = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var dataSource = new MyDataSource() { LastModified = expected };
+
+        // Act
+        var actual = await dataSource.GetLastModifiedAsync("/A/B/C", CancellationToken.None);
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public async Task LastModifiedIsUnknownByDefault()
+    {
+        // Arrange
+        IDataSource dataSource = new MyDataSource();
+
+        // Act
+        var actual = await dataSource.GetLastModifiedAsync("/A/B/C", CancellationToken.None);
+
+        // Assert
+        Assert.Null(actual);
+    }
+
+    [Fact]
+    public async Task CanReadStreamInChunks()
+    {
+        // Arrange
+        var dataSource = new MyDataSource();
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var options = new ReadStreamOptions() { ChunkSize = 10 };
+        var chunks = new List<ReadChunk>();
+
+        // Act
+        await foreach (var chunk in dataSource.ReadStreamAsync(begin, begin.AddSeconds(25), catalogItem, default!, options, CancellationToken.None))
+        {
+            chunks.Add(chunk);
+        }
+
+        // Assert
+        Assert.Equal(new int[] { 10, 10, 5 }, chunks.Select(chunk => chunk.Request.ElementCount));
+        Assert.Equal(begin.AddSeconds(20), chunks[^1].Begin);
+        Assert.Equal(begin.AddSeconds(25), chunks[^1].End);
+    }
+
+    [Fact]
+    public async Task CanReadStreamInRequestedDataType()
+    {
+        // Arrange
+        var dataSource = new RampDataSource();
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var options = new ReadStreamOptions() { ChunkSize = 10, RequestedDataType = NexusDataType.FLOAT32 };
+        var chunks = new List<(NexusDataType DataType, float[] Values)>();
+
+        // Act
+        await foreach (var chunk in dataSource.ReadStreamAsync(begin, begin.AddSeconds(25), catalogItem, default!, options, CancellationToken.None))
+        {
+            chunks.Add((chunk.Request.DataType, MemoryMarshal.Cast<byte, float>(chunk.Request.Data.Span).ToArray()));
+        }
+
+        // Assert
+        Assert.All(chunks, chunk => Assert.Equal(NexusDataType.FLOAT32, chunk.DataType));
+        Assert.Equal(Enumerable.Range(0, 10).Select(value => (float)value), chunks[0].Values);
+        Assert.Equal(Enumerable.Range(0, 10).Select(value => (float)value), chunks[1].Values);
+        Assert.Equal(Enumerable.Range(0, 5).Select(value => (float)value), chunks[2].Values);
+        Assert.All(dataSource.RequestedDataTypes, dataType => Assert.Null(dataType));
+    }
+
+    [Fact]
+    public async Task CanReadStreamInDataTypeProvidedBySource()
+    {
+        // Arrange
+        var dataSource = new RampDataSource() { ProvidesFloat32 = true };
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var options = new ReadStreamOptions() { ChunkSize = 10, RequestedDataType = NexusDataType.FLOAT32 };
+        var chunks = new List<float[]>();
+
+        // Act
+        await foreach (var chunk in dataSource.ReadStreamAsync(begin, begin.AddSeconds(10), catalogItem, default!, options, CancellationToken.None))
+        {
+            chunks.Add(MemoryMarshal.Cast<byte, float>(chunk.Request.Data.Span).ToArray());
+        }
+
+        // Assert
+        Assert.Equal(Enumerable.Range(0, 10).Select(value => (float)value), Assert.Single(chunks));
+        Assert.All(dataSource.RequestedDataTypes, dataType => Assert.Equal(NexusDataType.FLOAT32, dataType));
+    }
+
+    [Fact]
+    public async Task ReadStreamStopsWhenCancelled()
+    {
+        // Arrange
+        var dataSource = new GatedDataSource();
+        dataSource.Gate.SetResult();
+
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var options = new ReadStreamOptions() { ChunkSize = 10 };
+        var cts = new CancellationTokenSource();
+        var chunkCount = 0;
+
+        // Act
+        async Task action()
+        {
+            await foreach (var chunk in dataSource.ReadStreamAsync(begin, begin.AddSeconds(25), catalogItem, default!, options, cts.Token))
+            {
+                chunkCount++;
+                cts.Cancel();
+            }
+        }
+
+        // Assert
+        await Assert.ThrowsAnyAsync<OperationCanceledException>(action);
+        Assert.Equal(1, chunkCount);
+        Assert.Equal(1, dataSource.ReadCount);
+    }
+
+    [Fact]
+    public async Task CanReportCoveredRange()
+    {
+        // Arrange
+        var innerDataSource = new PartialDataSource();
+        var dataSource = new CoalescingDataSource(innerDataSource);
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var end = begin.AddSeconds(10);
+        var (data, status) = ExtensibilityUtilities.CreateBuffers(catalogItem.Representation, begin, end);
+        var request = new ReadRequest(catalogItem, data, status);
+
+        // Act
+        await dataSource.ReadAsync(begin, end, [request], default!, new Progress<double>(), CancellationToken.None);
+
+        // Assert
+        Assert.Equal((begin, begin.AddSeconds(6)), request.CoveredRange);
+        Assert.Equal(new byte[] { 1, 1, 1, 1, 1, 1, 0, 0, 0, 0 }, request.Status.ToArray());
+    }
+
+    [Fact]
+    public async Task CoveredRangeIsNotPartOfEquality()
+    {
+        // Arrange
+        var dataSource = new MyDataSource();
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var request = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+        var copy = request with { };
+
+        // Act
+        request.ReportCoveredRange(begin, begin.AddSeconds(6));
+
+        // Assert
+        Assert.Equal(request, copy);
+        Assert.Equal(request.GetHashCode(), copy.GetHashCode());
+        Assert.Null((request with { }).CoveredRange);
+        Assert.Throws<ArgumentException>(() => request.ReportCoveredRange(begin, begin.AddSeconds(-1)));
+    }
+
+    [Fact]
+    public async Task CanWriteCsvInChunks()
+    {
+        // Arrange
+        var dataSource = new MyDataSource();
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        using var writer = new StringWriter();
+
+        // Act
+        await ExtensibilityUtilities.WriteCsvAsync(writer, dataSource, catalogItem, begin, begin.AddSeconds(25), default!, chunkSize: 10, CancellationToken.None);
+
+        // Assert
+        var lines = writer
+            .ToString()
+            .Split(Environment.NewLine, StringSplitOptions.RemoveEmptyEntries);
+
+        Assert.Equal(26, lines.Length);
+        Assert.Equal("timestamp,Resource1", lines[0]);
+        Assert.Equal("2020-01-01T00:00:00.0000000Z,0", lines[1]);
+        Assert.Equal("2020-01-01T00:00:24.0000000Z,4", lines[25]);
+    }
+
+    [Fact]
+    public async Task WriteCsvWritesEmptyFieldsForNonFiniteValues()
+    {
+        // Arrange
+        var dataSource = new NonFiniteDataSource();
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        using var writer = new StringWriter();
+
+        // Act
+        await ExtensibilityUtilities.WriteCsvAsync(writer, dataSource, catalogItem, begin, begin.AddSeconds(4), default!, chunkSize: 10, CancellationToken.None);
+
+        // Assert
+        var lines = writer
+            .ToString()
+            .Split(Environment.NewLine, StringSplitOptions.RemoveEmptyEntries);
+
+        Assert.Equal("2020-01-01T00:00:00.0000000Z,0", lines[1]);
+        Assert.Equal("2020-01-01T00:00:01.0000000Z,", lines[2]);
+        Assert.Equal("2020-01-01T00:00:02.0000000Z,", lines[3]);
+        Assert.Equal("2020-01-01T00:00:03.0000000Z,3", lines[4]);
+    }
+
+    [Fact]
+    public async Task CanCoalesceConcurrentReads()
+    {
+        // Arrange
+        var innerDataSource = new GatedDataSource();
+        var dataSource = new CoalescingDataSource(innerDataSource);
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+
+        var request1 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+        var request2 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        // Act
+        var task1 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request1], default!, new Progress<double>(), CancellationToken.None);
+        var task2 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request2], default!, new Progress<double>(), CancellationToken.None);
+
+        innerDataSource.Gate.SetResult();
+        await Task.WhenAll(task1, task2);
+
+        // Assert
+        Assert.Equal(1, innerDataSource.ReadCount);
+        Assert.Equal(request1.Data.ToArray(), request2.Data.ToArray());
+        Assert.All(request2.Status.ToArray(), status => Assert.Equal(1, status));
+    }
+
+    [Fact]
+    public async Task CanContinueCoalescedReadWhenFirstCallerCancels()
+    {
+        // Arrange
+        var innerDataSource = new GatedDataSource();
+        var dataSource = new CoalescingDataSource(innerDataSource);
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var cts = new CancellationTokenSource();
+
+        var request1 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+        var request2 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        // Act
+        var task1 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request1], default!, new Progress<double>(), cts.Token);
+        var task2 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request2], default!, new Progress<double>(), CancellationToken.None);
+
+        cts.Cancel();
+        await Assert.ThrowsAnyAsync<OperationCanceledException>(() => task1);
+
+        innerDataSource.Gate.SetResult();
+        await task2;
+
+        // Assert
+        Assert.Equal(1, innerDataSource.ReadCount);
+        Assert.All(request2.Status.ToArray(), status => Assert.Equal(1, status));
+    }
+
+    [Fact]
+    public async Task CanCancelCoalescedReadWhenAllCallersCancel()
+    {
+        // Arrange
+        var innerDataSource = new GatedDataSource();
+        var dataSource = new CoalescingDataSource(innerDataSource);
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var cts = new CancellationTokenSource();
+        var request = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        // Act
+        var task = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request], default!, new Progress<double>(), cts.Token);
+        var innerCancellationToken = await innerDataSource.Started.Task;
+
+        cts.Cancel();
+
+        // Assert
+        await Assert.ThrowsAnyAsync<OperationCanceledException>(() => task);
+        Assert.True(innerCancellationToken.IsCancellationRequested);
+    }
+
+    [Fact]
+    public async Task CanForwardProgressToCoalescedCallers()
+    {
+        // Arrange
+        var innerDataSource = new GatedDataSource();
+        var dataSource = new CoalescingDataSource(innerDataSource);
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var progress1 = new RecordingProgress();
+        var progress2 = new RecordingProgress();
+
+        var request1 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+        var request2 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        // Act
+        var task1 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request1], default!, progress1, CancellationToken.None);
+        var task2 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request2], default!, progress2, CancellationToken.None);
+
+        innerDataSource.Gate.SetResult();
+        await Task.WhenAll(task1, task2);
+
+        // Assert
+        Assert.Equal(1, innerDataSource.ReadCount);
+        Assert.Contains(0.5, progress1.Values);
+        Assert.Contains(0.5, progress2.Values);
+        Assert.Equal(1.0, progress1.Values.Last());
+        Assert.Equal(1.0, progress2.Values.Last());
+    }
+
+    [Fact]
+    public async Task DoesNotCoalesceReadsWithDifferentReadDataHandlers()
+    {
+        // Arrange
+        var innerDataSource = new GatedDataSource();
+        var dataSource = new CoalescingDataSource(innerDataSource);
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+
+        var request1 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+        var request2 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        ReadDataHandler readData1 = (_, _, _, _, _) => Task.CompletedTask;
+        ReadDataHandler readData2 = (_, _, _, _, _) => Task.CompletedTask;
+
+        // Act
+        var task1 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request1], readData1, new Progress<double>(), CancellationToken.None);
+        var task2 = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request2], readData2, new Progress<double>(), CancellationToken.None);
+
+        innerDataSource.Gate.SetResult();
+        await Task.WhenAll(task1, task2);
+
+        // Assert
+        Assert.Equal(2, innerDataSource.ReadCount);
+    }
+
+    [Fact]
+    public async Task CanForwardCoalescedRequestsAsBatch()
+    {
+        // Arrange
+        var innerDataSource = new GatedDataSource();
+        var dataSource = new CoalescingDataSource(innerDataSource);
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+
+        var request1 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+        var request2 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        var request3 = new ReadRequest(catalogItem, new byte[10 * sizeof(float)], new byte[10])
+        {
+            RequestedDataType = NexusDataType.FLOAT32
+        };
+
+        var request4 = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10])
+        {
+            Quality = new byte[10]
+        };
+
+        // Act
+        var task = dataSource.ReadAsync(begin, begin.AddSeconds(10), [request1, request2, request3, request4], default!, new Progress<double>(), CancellationToken.None);
+
+        innerDataSource.Gate.SetResult();
+        await task;
+
+        // Assert
+        Assert.Equal(1, innerDataSource.ReadCount);
+        Assert.Equal(3, innerDataSource.LastRequestCount);
+        Assert.Equal(request1.Data.ToArray(), request2.Data.ToArray());
+        Assert.Equal(Enumerable.Range(0, 10).Select(value => (float)value), MemoryMarshal.Cast<byte, float>(request3.Data.Span).ToArray());
+        Assert.All(request4.Quality!.Value.ToArray(), quality => Assert.Equal(192, quality));
+    }
+
+    [Fact]
+    public void CanDeclareResamplingSupport()
+    {
+        // Arrange
+        IDataSource dataSource = new ResamplingDataSource();
+        IDataSource defaultDataSource = new MyDataSource();
+
+        // Act
+        var actual1 = dataSource.CanResample("/A/B/C", "Resource1");
+        var actual2 = dataSource.CanResample("/A/B/C", "Resource2");
+        var actual3 = defaultDataSource.CanResample("/A/B/C", "Resource1");
+
+        // Assert
+        Assert.True(actual1);
+        Assert.False(actual2);
+        Assert.False(actual3);
+    }
+
+    [Fact]
+    public async Task CanRunConformanceSuite()
+    {
+        // Arrange
+        var dataSource = new MyDataSource();
+
+        // Act
+        var report = await DataSourceConformance.RunAsync(dataSource, "/A/B/C", CancellationToken.None);
+
+        // Assert
+        Assert.True(report.Passed, string.Join(Environment.NewLine, report.Checks.Select(check => $"{check.Name}: {check.Message}")));
+        Assert.Contains(report.Checks, check => check.Name == "read-status");
+    }
+
+    [Fact]
+    public async Task ConformanceSuiteDetectsBrokenSource()
+    {
+        // Arrange
+        var dataSource = new BrokenDataSource();
+
+        // Act
+        var report = await DataSourceConformance.RunAsync(dataSource, "/A/B/C", CancellationToken.None);
+
+        // Assert
+        var failedChecks = report.Checks
+            .Where(check => !check.Passed)
+            .Select(check => check.Name);
+
+        Assert.False(report.Passed);
+        Assert.Equal(new[] { "time-range-alignment", "read-covered-range", "read-status", "read-status-consistency" }, failedChecks);
+    }
+
+    [Fact]
+    public async Task CanDelegateReadToReadDataHandler()
+    {
+        // Arrange
+        var dataSource = new DelegatingDataSource();
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var request = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        static Task readData(string resourcePath, DateTime begin, DateTime end, Memory<double> buffer, CancellationToken cancellationToken)
+        {
+            for (int i = 0; i < buffer.Length; i++)
+            {
+                buffer.Span[i] = i;
+            }
+
+            return Task.CompletedTask;
+        }
+
+        // Act
+        await dataSource.ReadAsync(begin, begin.AddSeconds(10), [request], readData, new Progress<double>(), CancellationToken.None);
+
+        // Assert
+        Assert.Equal(Enumerable.Range(0, 10).Select(value => (double)value), MemoryMarshal.Cast<byte, double>(request.Data.Span).ToArray());
+    }
+
+    [Fact]
+    public async Task ReadDataHandlerErrorsPropagate()
+    {
+        // Arrange
+        var dataSource = new DelegatingDataSource();
+        var catalogItem = (await dataSource.GetCatalogAsync("/A/B/C", CancellationToken.None)).Find("/A/B/C/Resource1/1_s");
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var request = new ReadRequest(catalogItem, new byte[10 * sizeof(double)], new byte[10]);
+
+        static async Task readData(string resourcePath, DateTime begin, DateTime end, Memory<double> buffer, CancellationToken cancellationToken)
+        {
+            await Task.Yield();
+            throw new IOException("The underlying storage is not available.");
+        }
+
+        // Act
+        Task action() => dataSource.ReadAsync(begin, begin.AddSeconds(10), [request], readData, new Progress<double>(), CancellationToken.None);
+
+        // Assert
+        await Assert.ThrowsAsync<IOException>(action);
+    }
+
+    [Fact]
+    public void ContextValidationThrowsForMissingRequestKey()
+    {
+        // Arrange
+        var context = new DataSourceContext(
+            ResourceLocator: new Uri("file:///data"),
+            SystemConfiguration: default,
+            SourceConfiguration: default,
+            RequestConfiguration: new Dictionary<string, JsonElement>()
+            {
+                ["user"] = JsonSerializer.SerializeToElement("foo")
+            });
+
+        // Act
+        void action() => context.Validate(["user", "password"]);
+
+        // Assert
+        var exception = Assert.Throws<ArgumentException>(action);
+        Assert.Contains("password", exception.Message);
+    }
+
+    [Fact]
+    public void CanValidateContext()
+    {
+        // Arrange
+        var context = new DataSourceContext(
+            ResourceLocator: new Uri("file:///data"),
+            SystemConfiguration: default,
+            SourceConfiguration: default,
+            RequestConfiguration: new Dictionary<string, JsonElement>()
+            {
+                ["user"] = JsonSerializer.SerializeToElement("foo"),
+                ["password"] = JsonSerializer.SerializeToElement("bar")
+            });
+
+        // Act
+        context.Validate(["user", "password"], requiresResourceLocator: true);
+    }
+
+    [Fact]
+    public void CanBuildContext()
+    {
+        // Arrange
+        var sourceConfiguration = new Dictionary<string, JsonElement>()
+        {
+            ["foo"] = JsonSerializer.SerializeToElement("bar")
+        };
+
+        var requestConfiguration = new Dictionary<string, JsonElement>()
+        {
+            ["user"] = JsonSerializer.SerializeToElement("foo")
+        };
+
+        // Act
+        var context1 = new DataSourceContextBuilder(sourceConfiguration)
+            .Build();
+
+        var context2 = new DataSourceContextBuilder(sourceConfiguration)
+            .WithResourceLocator(new Uri("file:///data"))
+            .WithRequestConfiguration(requestConfiguration)
+            .Build();
+
+        // Assert
+        Assert.Null(context1.ResourceLocator);
+        Assert.Null(context1.RequestConfiguration);
+        Assert.Equal(sourceConfiguration, context1.SourceConfiguration);
+
+        Assert.Equal(new Uri("file:///data"), context2.ResourceLocator);
+        Assert.Equal(requestConfiguration, context2.RequestConfiguration);
+        context2.Validate(["user"], requiresResourceLocator: true);
+    }
+
+    [Theory]
+    [InlineData("user", true)]
+    [InlineData("my_source.user", true)]
+    [InlineData("my-source.user", false)]
+    [InlineData("my_source..user", false)]
+    [InlineData("9user", false)]
+    public void CanValidateRequestKeys(string key, bool isValid)
+    {
+        // Arrange
+        var context = new DataSourceContext(
+            ResourceLocator: default,
+            SystemConfiguration: default,
+            SourceConfiguration: default,
+            RequestConfiguration: new Dictionary<string, JsonElement>()
+            {
+                [key] = JsonSerializer.SerializeToElement("foo")
+            });
+
+        // Act
+        void action() => context.ValidateRequestKeys();
+
+        // Assert
+        if (isValid)
+            action();
+
+        else
+            Assert.Throws<ArgumentException>(action);
+    }
+
+    [Fact]
+    public void CanCalculateReadRequestElementCount()
+    {
+        // Arrange
+        var representation = new Representation(NexusDataType.INT16, TimeSpan.FromSeconds(1));
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+        var catalog = new ResourceCatalog(id: "/A/B/C", resources: [resource]);
+        var catalogItem = new CatalogItem(catalog, resource, representation, Parameters: default);
+
+        var readRequest = new ReadRequest(catalogItem, new byte[10 * sizeof(short)], new byte[10]);
+
+        // Act
+        var actual = readRequest.ElementCount;
+
+        // Assert
+        Assert.Equal(10, actual);
+    }
+
+    private class MyDataSource : SimpleDataSource
+    {
+        public DateTime? LastModified { get; init; }
+
+        public override Task<CatalogRegistration[]> GetCatalogRegistrationsAsync(
+            string path,
+            CancellationToken cancellationToken)
+        {
+            return Task.FromResult(new CatalogRegistration[]
+            {
+                new("/A/B/C", "Catalog C"),
+                new("/A/B/D", "Catalog D"),
+                new("/A/B/E", default, IsTransient: true)
+            });
+        }
+
+        public override Task<ResourceCatalog> GetCatalogAsync(
+            string catalogId,
+            CancellationToken cancellationToken)
+        {
+            var representation = new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1));
+
+            var resource = new ResourceBuilder(id: "Resource1")
+                .AddRepresentation(representation)
+                .Build();
+
+            var catalog = new ResourceCatalogBuilder(id: catalogId)
+                .AddResource(resource)
+                .Build();
+
+            return Task.FromResult(catalog);
+        }
+
+        public override Task<DateTime?> GetLastModifiedAsync(
+            string catalogId,
+            CancellationToken cancellationToken)
+        {
+            return LastModified is null
+                ? base.GetLastModifiedAsync(catalogId, cancellationToken)
+                : Task.FromResult(LastModified);
+        }
+
+        public override Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            foreach (var request in requests)
+            {
+                var data = MemoryMarshal.Cast<byte, double>(request.Data.Span);
+
+                for (int i = 0; i < data.Length; i++)
+                {
+                    data[i] = i;
+                }
+
+                request.Status.Span.Fill(1);
+                request.Quality?.Span.Fill(192);
+            }
+
+            progress.Report(1.0);
+
+            return Task.CompletedTask;
+        }
+    }
+
+    private class GatedDataSource : MyDataSource
+    {
+        private int _readCount;
+
+        public TaskCompletionSource Gate { get; } = new();
+
+        public TaskCompletionSource<CancellationToken> Started { get; } = new();
+
+        public int ReadCount => _readCount;
+
+        public int LastRequestCount { get; private set; }
+
+        public override async Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            Interlocked.Increment(ref _readCount);
+            LastRequestCount = requests.Length;
+            Started.TrySetResult(cancellationToken);
+
+            await Gate.Task.WaitAsync(cancellationToken);
+            progress.Report(0.5);
+
+            await base.ReadAsync(begin, end, requests, readData, progress, cancellationToken);
+        }
+    }
+
+    private class RecordingProgress : IProgress<double>
+    {
+        private readonly List<double> _values = [];
+
+        public IReadOnlyList<double> Values
+        {
+            get
+            {
+                lock (_values)
+                {
+                    return [.. _values];
+                }
+            }
+        }
+
+        public void Report(double value)
+        {
+            lock (_values)
+            {
+                _values.Add(value);
+            }
+        }
+    }
+
+    /* provides data for the first 6 seconds only */
+    private class PartialDataSource : MyDataSource
+    {
+        public override async Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            await base.ReadAsync(begin, end, requests, readData, progress, cancellationToken);
+
+            var coveredEnd = begin.AddSeconds(6);
+
+            foreach (var request in requests)
+            {
+                request.Status.Span[6..].Clear();
+                request.ReportCoveredRange(begin, coveredEnd);
+            }
+        }
+    }
+
+    /* provides NaN and infinity at the second and third sample, both marked as valid */
+    private class NonFiniteDataSource : MyDataSource
+    {
+        public override async Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            await base.ReadAsync(begin, end, requests, readData, progress, cancellationToken);
+
+            foreach (var request in requests)
+            {
+                var data = MemoryMarshal.Cast<byte, double>(request.Data.Span);
+
+                data[1] = double.NaN;
+                data[2] = double.PositiveInfinity;
+            }
+        }
+    }
+
+    /* provides an INT16 ramp which starts at zero for each read */
+    private class RampDataSource : MyDataSource
+    {
+        public bool ProvidesFloat32 { get; init; }
+
+        public List<NexusDataType?> RequestedDataTypes { get; } = [];
+
+        public override Task<ResourceCatalog> GetCatalogAsync(
+            string catalogId,
+            CancellationToken cancellationToken)
+        {
+            var representation = new Representation(NexusDataType.INT16, TimeSpan.FromSeconds(1));
+
+            var resource = new ResourceBuilder(id: "Resource1")
+                .AddRepresentation(representation)
+                .Build();
+
+            var catalog = new ResourceCatalogBuilder(id: catalogId)
+                .AddResource(resource)
+                .Build();
+
+            return Task.FromResult(catalog);
+        }
+
+        public override bool CanProvideDataType(
+            string catalogId,
+            string resourceId,
+            NexusDataType dataType)
+        {
+            return ProvidesFloat32 && dataType == NexusDataType.FLOAT32;
+        }
+
+        public override Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            foreach (var request in requests)
+            {
+                RequestedDataTypes.Add(request.RequestedDataType);
+
+                var ramp = Enumerable
+                    .Range(0, request.ElementCount)
+                    .Select(value => (short)value)
+                    .ToArray();
+
+                ExtensibilityUtilities.WriteData<short>(request, ramp);
+                request.Status.Span.Fill(1);
+            }
+
+            return Task.CompletedTask;
+        }
+    }
+
+    /* violates the alignment and status rules which are checked by the conformance suite */
+    private class BrokenDataSource : MyDataSource
+    {
+        public override Task<(DateTime Begin, DateTime End)> GetTimeRangeAsync(
+            string catalogId,
+            CancellationToken cancellationToken)
+        {
+            var begin = new DateTime(2020, 01, 01, 0, 0, 0, 500, DateTimeKind.Utc);
+
+            return Task.FromResult((begin, begin.AddDays(1)));
+        }
+
+        public override async Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            await base.ReadAsync(begin, end, requests, readData, progress, cancellationToken);
+
+            foreach (var request in requests)
+            {
+                MemoryMarshal.Cast<byte, double>(request.Data.Span)[0] = double.NaN;
+                request.Status.Span[1] = 2;
+                request.ReportCoveredRange(begin, begin.AddMilliseconds(1500));
+            }
+        }
+    }
+
+    private class ResamplingDataSource : MyDataSource
+    {
+        public override bool CanResample(
+            string catalogId,
+            string resourceId)
+        {
+            return resourceId == "Resource1";
+        }
+    }
+
+    private class DelegatingDataSource : MyDataSource
+    {
+        public override async Task ReadAsync(
+            DateTime begin,
+            DateTime end,
+            ReadRequest[] requests,
+            ReadDataHandler readData,
+            IProgress<double> progress,
+            CancellationToken cancellationToken)
+        {
+            foreach (var request in requests)
+            {
+                var buffer = new double[request.ElementCount];
+
+                await readData("/A/B/C/Source/1_s", begin, end, buffer, cancellationToken);
+
+                buffer.CopyTo(MemoryMarshal.Cast<byte, double>(request.Data.Span));
+                request.Status.Span.Fill(1);
+            }
+        }
+    }
+}
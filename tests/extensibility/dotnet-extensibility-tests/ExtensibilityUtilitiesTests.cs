@@ -0,0 +1,780 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using Nexus.DataModel;
+using System.Buffers;
+using System.Runtime.InteropServices;
+using System.Text;
+using System.Text.Json;
+using Xunit;
+
+namespace Nexus.Extensibility.Tests;
+
+public class ExtensibilityUtilitiesTests
+{
+    [Theory]
+    [InlineData(NexusDataType.FLOAT64, "00:00:01", 10)]
+    [InlineData(NexusDataType.INT16, "00:00:00.1", 100)]
+    [InlineData(NexusDataType.UINT8, "00:01:00", 0)]
+    public void BufferSizesMatchCreatedBuffers(NexusDataType dataType, string samplePeriodString, int expectedElementCount)
+    {
+        // Arrange
+        var representation = new Representation(dataType, TimeSpan.Parse(samplePeriodString));
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var end = begin.AddSeconds(10);
+
+        // Act
+        var (dataLength, statusLength) = ExtensibilityUtilities.GetBufferSizes(representation, begin, end);
+        var (data, status) = ExtensibilityUtilities.CreateBuffers(representation, begin, end);
+
+        // Assert
+        Assert.Equal(expectedElementCount, statusLength);
+        Assert.Equal(expectedElementCount * representation.ElementSize, dataLength);
+        Assert.Equal(data.Length, dataLength);
+        Assert.Equal(status.Length, statusLength);
+    }
+
+    [Fact]
+    public void CanFlagOutOfRange()
+    {
+        // Arrange
+        var values = new double[] { -1, 0, 50, 100, 101 };
+        var status = new byte[] { 1, 1, 1, 1, 1 };
+
+        // Act
+        ExtensibilityUtilities.FlagOutOfRange(values, status, min: 0, max: 100);
+
+        // Assert
+        Assert.Equal(new double[] { double.NaN, 0, 50, 100, double.NaN }, values);
+        Assert.Equal(new byte[] { 0, 1, 1, 1, 0 }, status);
+    }
+
+    [Theory]
+    [InlineData(new double[] { 1, 0, 1 }, new int[] { 1, 2 })]
+    [InlineData(new double[] { 1, 0, 0.1, 1, 0 }, new int[] { 1, 3, 4, 5 })]
+    [InlineData(new double[] { 1, 0.9, 1 }, new int[] { })]
+    public void CanGetGapsFromAvailability(double[] dayValues, int[] expectedDayBounds)
+    {
+        // Arrange
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+
+        // Act
+        var actual = ExtensibilityUtilities.GetGapsFromAvailability(begin, dayValues, threshold: 0.5);
+
+        // Assert
+        var expected = expectedDayBounds
+            .Chunk(2)
+            .Select(bounds => (begin.AddDays(bounds[0]), begin.AddDays(bounds[1])));
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Theory]
+    [InlineData(new double[] { 1, 1, 1 }, new double[] { 1, 0.9, 1 }, new double[] { 1, 1, 1 })]
+    [InlineData(new double[] { 1, 1, 0 }, new double[] { 0, 1, 1 }, new double[] { 0, 1, 0 })]
+    [InlineData(new double[] { 1, 0, 0 }, new double[] { 0, 0, 1 }, new double[] { 0, 0, 0 })]
+    public void CanGetCommonAvailability(double[] dayValues1, double[] dayValues2, double[] expected)
+    {
+        // Act
+        var actual = ExtensibilityUtilities.GetCommonAvailability([dayValues1, dayValues2], threshold: 0.5);
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void CanReuseCallerProvidedBuffers()
+    {
+        // Arrange
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var end = begin.AddSeconds(10);
+
+        var catalogItems = new[]
+        {
+            CreateCatalogItem(NexusDataType.FLOAT64),
+            CreateCatalogItem(NexusDataType.INT16)
+        };
+
+        var data = new byte[10 * sizeof(double) + 10 * sizeof(short)];
+        var status = new byte[20];
+
+        // Act
+        var requests1 = ExtensibilityUtilities.CreateReadRequests(catalogItems, begin, end, data, status);
+        requests1[0].Status.Span.Fill(1);
+
+        var requests2 = ExtensibilityUtilities.CreateReadRequests(catalogItems, begin, end, data, status);
+
+        // Assert
+        foreach (var request in requests1.Concat(requests2))
+        {
+            Assert.True(MemoryMarshal.TryGetArray<byte>(request.Data, out var dataSegment));
+            Assert.True(MemoryMarshal.TryGetArray<byte>(request.Status, out var statusSegment));
+
+            Assert.Same(data, dataSegment.Array);
+            Assert.Same(status, statusSegment.Array);
+        }
+
+        Assert.Equal(10, requests2[0].ElementCount);
+        Assert.Equal(10, requests2[1].ElementCount);
+        Assert.All(status, value => Assert.Equal(0, value));
+    }
+
+    [Fact]
+    public void CanGenerateTimestampsNs()
+    {
+        // Arrange
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var samplePeriod = TimeSpan.FromMilliseconds(10);
+        var count = 1_000_000;
+
+        // Act
+        var actual = ExtensibilityUtilities
+            .GetTimestampsNs(begin, samplePeriod, count)
+            .ToArray();
+
+        // Assert
+        static long ToUnixNs(DateTime timestamp) => (timestamp - DateTime.UnixEpoch).Ticks * 100;
+
+        Assert.Equal(count, actual.Length);
+        Assert.Equal(ToUnixNs(begin), actual[0]);
+        Assert.Equal(ToUnixNs(begin + (count - 1) * samplePeriod), actual[^1]);
+    }
+
+    [Fact]
+    public void GetTimestampsNsThrowsForOutOfRangeTimestamps()
+    {
+        // Arrange
+        var begin = new DateTime(2262, 04, 11, 0, 0, 0, DateTimeKind.Utc);
+        var samplePeriod = TimeSpan.FromDays(1);
+
+        // Act
+        var actual = ExtensibilityUtilities.GetTimestampsNs(begin, samplePeriod, count: 1).Single();
+
+        // Assert
+        Assert.True(actual > 0);
+        Assert.Throws<OverflowException>(() => ExtensibilityUtilities.GetTimestampsNs(begin, samplePeriod, count: 2));
+        Assert.Throws<OverflowException>(() => ExtensibilityUtilities.GetTimestampsNs(begin.AddDays(1), samplePeriod, count: 1));
+    }
+
+    [Fact]
+    public void CanEstimateBatchBytes()
+    {
+        // Arrange
+        var requests = new[]
+        {
+            new ReadRequest(CreateCatalogItem(NexusDataType.FLOAT64), new byte[80], new byte[10]),
+            new ReadRequest(CreateCatalogItem(NexusDataType.INT16), new byte[20], new byte[10])
+        };
+
+        // Act
+        var actual = ExtensibilityUtilities.EstimateBatchBytes(requests);
+
+        // Assert
+        Assert.Equal(120, actual);
+    }
+
+    [Fact]
+    public void EstimateBatchBytesDoesNotOverflowForLargeBatches()
+    {
+        // Arrange
+        var memory = new FakeMemoryManager().Memory;
+
+        var requests = new[]
+        {
+            new ReadRequest(CreateCatalogItem(NexusDataType.UINT8), memory, memory),
+            new ReadRequest(CreateCatalogItem(NexusDataType.UINT8), memory, memory)
+        };
+
+        // Act
+        var actual = ExtensibilityUtilities.EstimateBatchBytes(requests);
+
+        // Assert
+        Assert.Equal(4L * int.MaxValue, actual);
+    }
+
+    [Theory]
+    [InlineData(new[] { 1, 1, 1 }, true)]
+    [InlineData(new[] { 1, 10, 1 }, false)]
+    public void CanDetectUniformBatchPeriod(int[] periodsInSeconds, bool expected)
+    {
+        // Arrange
+        var requests = periodsInSeconds
+            .Select(period => new ReadRequest(
+                CreateCatalogItem(NexusDataType.FLOAT64, TimeSpan.FromSeconds(period)),
+                Memory<byte>.Empty,
+                Memory<byte>.Empty))
+            .ToArray();
+
+        // Act
+        var actual = ExtensibilityUtilities.BatchIsUniformPeriod(requests);
+        var actualPeriods = ExtensibilityUtilities.GetBatchPeriods(requests);
+
+        // Assert
+        Assert.Equal(expected, actual);
+        Assert.Equal(periodsInSeconds.Distinct().Order().Select(period => TimeSpan.FromSeconds(period)), actualPeriods);
+    }
+
+    [Fact]
+    public void CanPartitionRequestsByCatalog()
+    {
+        // Arrange
+        var catalogItem1 = CreateCatalogItem(NexusDataType.FLOAT64);
+
+        var catalogItem2 = catalogItem1 with
+        {
+            Catalog = new ResourceCatalog(id: "/X/Y", resources: [catalogItem1.Resource])
+        };
+
+        var request1 = new ReadRequest(catalogItem1, new byte[8], new byte[1]);
+        var request2 = new ReadRequest(catalogItem2, new byte[8], new byte[1]);
+        var request3 = new ReadRequest(catalogItem1, new byte[16], new byte[2]);
+
+        // Act
+        var actual = ExtensibilityUtilities.PartitionRequestsByCatalog([request1, request2, request3]);
+
+        // Assert
+        Assert.Equal(2, actual.Count);
+        Assert.Equal(new ReadRequest[] { request1, request3 }, actual["/A/B/C"]);
+        Assert.Equal(new ReadRequest[] { request2 }, actual["/X/Y"]);
+    }
+
+    [Fact]
+    public void CanWriteDataInRequestedDataType()
+    {
+        // Arrange
+        var catalogItem = CreateCatalogItem(NexusDataType.INT16);
+        var ramp = Enumerable.Range(0, 10).Select(value => (short)value).ToArray();
+
+        var request = new ReadRequest(catalogItem, new byte[ramp.Length * sizeof(float)], new byte[ramp.Length])
+        {
+            RequestedDataType = NexusDataType.FLOAT32
+        };
+
+        // Act
+        ExtensibilityUtilities.WriteData<short>(request, ramp);
+
+        // Assert
+        var expected = ramp.Select(value => (float)value).ToArray();
+        var actual = MemoryMarshal.Cast<byte, float>(request.Data.Span).ToArray();
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void WriteDataSaturatesWhenNarrowing()
+    {
+        // Arrange
+        var catalogItem = CreateCatalogItem(NexusDataType.FLOAT64);
+        var values = new double[] { -300, 1, 300 };
+
+        var request = new ReadRequest(catalogItem, new byte[values.Length], new byte[values.Length])
+        {
+            RequestedDataType = NexusDataType.INT8
+        };
+
+        // Act
+        ExtensibilityUtilities.WriteData<double>(request, values);
+
+        // Assert
+        var actual = MemoryMarshal.Cast<byte, sbyte>(request.Data.Span).ToArray();
+
+        Assert.Equal(new sbyte[] { -128, 1, 127 }, actual);
+    }
+
+    [Fact]
+    public void WriteDataMatchesEncodeInto()
+    {
+        // Arrange
+        var catalogItem = CreateCatalogItem(NexusDataType.FLOAT64);
+        var values = new double[] { double.NaN, double.PositiveInfinity, -1e10, 3.7 };
+        var expected = new byte[values.Length * sizeof(short)];
+
+        var request = new ReadRequest(catalogItem, new byte[values.Length * sizeof(short)], new byte[values.Length])
+        {
+            RequestedDataType = NexusDataType.INT16
+        };
+
+        // Act
+        ExtensibilityUtilities.WriteData<double>(request, values);
+        ExtensibilityUtilities.EncodeInto(expected, values, NexusDataType.INT16);
+
+        // Assert
+        Assert.Equal(expected, request.Data.ToArray());
+    }
+
+    [Fact]
+    public void CanEncodeIntoBuffer()
+    {
+        // Arrange
+        var values = new double[] { 1, 2, 3 };
+        var destination = new byte[values.Length * sizeof(ushort)];
+
+        // Act
+        var bytesWritten = ExtensibilityUtilities.EncodeInto(destination, values, NexusDataType.UINT16);
+
+        // Assert
+        Assert.Equal(destination.Length, bytesWritten);
+        Assert.Equal(new ushort[] { 1, 2, 3 }, MemoryMarshal.Cast<byte, ushort>(destination).ToArray());
+    }
+
+    [Fact]
+    public void EncodeIntoThrowsForTooSmallBuffer()
+    {
+        // Arrange
+        var values = new double[] { 1, 2, 3 };
+        var destination = new byte[values.Length * sizeof(ushort) - 1];
+
+        // Act
+        void action() => ExtensibilityUtilities.EncodeInto(destination, values, NexusDataType.UINT16);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+    }
+
+    [Theory]
+    [InlineData(new double[] { 1, 5, 9 }, new double[] { 1, 5, 9 })]
+    [InlineData(new double[] { 80, 95, 3, 10 }, new double[] { 80, 95, 103, 110 })]
+    public void CanUnwrapCounter(double[] values, double[] expected)
+    {
+        var actual = ExtensibilityUtilities.UnwrapCounter(values, max: 100);
+
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void CanEvaluateExpression()
+    {
+        // Arrange
+        var inputs = new Dictionary<string, ReadOnlyMemory<double>>()
+        {
+            ["a"] = new double[] { 1, 2, 3 },
+            ["b"] = new double[] { 10, 20, 30 }
+        };
+
+        // Act
+        var actual = ExtensibilityUtilities.EvaluateExpression("a + b", inputs);
+
+        // Assert
+        Assert.Equal(new double[] { 11, 22, 33 }, actual);
+    }
+
+    [Fact]
+    public void EvaluateExpressionReturnsNaNForDivisionByZero()
+    {
+        // Arrange
+        var inputs = new Dictionary<string, ReadOnlyMemory<double>>()
+        {
+            ["a"] = new double[] { 1, 2, 3 },
+            ["b"] = new double[] { 2, 0, 4 }
+        };
+
+        // Act
+        var actual = ExtensibilityUtilities.EvaluateExpression("(a * 2) / b", inputs);
+
+        // Assert
+        Assert.Equal(new double[] { 1, double.NaN, 1.5 }, actual);
+    }
+
+    [Fact]
+    public void EvaluateExpressionThrowsForUnknownIdentifier()
+    {
+        // Arrange
+        var inputs = new Dictionary<string, ReadOnlyMemory<double>>()
+        {
+            ["a"] = new double[] { 1, 2, 3 }
+        };
+
+        // Act
+        void action() => ExtensibilityUtilities.EvaluateExpression("a + c", inputs);
+
+        // Assert
+        var exception = Assert.Throws<ArgumentException>(action);
+        Assert.Contains("unknown input c", exception.Message);
+    }
+
+    [Fact]
+    public void EvaluateExpressionThrowsForMismatchedInputLengths()
+    {
+        // Arrange
+        var inputs = new Dictionary<string, ReadOnlyMemory<double>>()
+        {
+            ["a"] = new double[] { 1, 2, 3 },
+            ["b"] = new double[] { 10, 20 }
+        };
+
+        // Act
+        void action() => ExtensibilityUtilities.EvaluateExpression("a + b", inputs);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+    }
+
+    [Fact]
+    public void EvaluateExpressionThrowsForMissingInputs()
+    {
+        // Arrange
+        var inputs = new Dictionary<string, ReadOnlyMemory<double>>();
+
+        // Act
+        void action() => ExtensibilityUtilities.EvaluateExpression("1 + 2", inputs);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+    }
+
+    [Fact]
+    public void CanGetResourceIdUnion()
+    {
+        // Arrange
+        var catalog1 = new ResourceCatalog(id: "/A/B/C", resources: [new Resource(id: "R3"), new Resource(id: "R1")]);
+        var catalog2 = new ResourceCatalog(id: "/A/B/C", resources: [new Resource(id: "R1"), new Resource(id: "R2")]);
+
+        // Act
+        var actual = ExtensibilityUtilities.GetResourceIdUnion(catalog1, catalog2);
+
+        // Assert
+        Assert.Equal(new string[] { "R1", "R2", "R3" }, actual);
+    }
+
+    [Fact]
+    public void CanRenderCatalogTree()
+    {
+        // Arrange
+        var resource = new ResourceBuilder(id: "Resource1")
+            .WithUnit("m/s")
+            .AddRepresentation(new Representation(NexusDataType.FLOAT64, TimeSpan.FromSeconds(1)))
+            .Build();
+
+        var catalog = new ResourceCatalogBuilder(id: "/A/B/C")
+            .AddResource(resource)
+            .Build();
+
+        // Act
+        var actual = ExtensibilityUtilities.RenderCatalogTree(catalog);
+
+        // Assert
+        Assert.StartsWith("/A/B/C", actual);
+        Assert.Contains("  Resource1 (m/s)", actual);
+        Assert.Contains("    1_s (FLOAT64)", actual);
+    }
+
+    [Theory]
+    [InlineData(new byte[] { 1, 1, 1, 1 }, new double[] { 1, 1 })]
+    [InlineData(new byte[] { 0, 0, 0, 0 }, new double[] { 0, 0 })]
+    [InlineData(new byte[] { 1, 0, 0, 1 }, new double[] { 0.5, 0.5 })]
+    [InlineData(new byte[] { 1, 1, 0, 1, 1 }, new double[] { 1, 0.5, 1 })]
+    public void CanGetAvailabilityFromStatus(byte[] status, double[] expected)
+    {
+        // Act
+        var actual = ExtensibilityUtilities.GetAvailabilityFromStatus(status, elementsPerBucket: 2);
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void CanDeriveStatusFromQuality()
+    {
+        // Arrange
+        var catalogItem = CreateCatalogItem(NexusDataType.FLOAT64);
+
+        var request = new ReadRequest(catalogItem, new byte[4 * sizeof(double)], new byte[4])
+        {
+            Quality = new byte[] { 0, 99, 100, 255 }
+        };
+
+        // Act
+        ExtensibilityUtilities.DeriveStatusFromQuality(request.Quality.Value.Span, request.Status.Span, threshold: 100);
+
+        // Assert
+        Assert.Equal(new byte[] { 0, 0, 1, 1 }, request.Status.ToArray());
+    }
+
+    [Theory]
+    [InlineData(StatusMergeMode.And, new byte[] { 1, 0, 0, 0 })]
+    [InlineData(StatusMergeMode.Or, new byte[] { 1, 1, 1, 0 })]
+    public void CanMergeStatus(StatusMergeMode mode, byte[] expected)
+    {
+        // Arrange
+        var status1 = new byte[] { 1, 1, 0, 0 };
+        var status2 = new byte[] { 1, 0, 1, 0 };
+
+        // Act
+        var actual = ExtensibilityUtilities.MergeStatus(status1, status2, mode);
+
+        // Assert
+        Assert.Equal(expected, actual);
+    }
+
+    [Fact]
+    public void CanMergeDataFromBackup()
+    {
+        // Arrange
+        var primary = new double[] { 1, double.NaN, double.NaN, 4 };
+        var primaryStatus = new byte[] { 1, 0, 0, 1 };
+        var backup = new double[] { 10, 20, double.NaN, 40 };
+        var backupStatus = new byte[] { 1, 1, 0, 1 };
+
+        // Act
+        ExtensibilityUtilities.MergeData(primary, primaryStatus, backup, backupStatus);
+
+        // Assert
+        Assert.Equal(new double[] { 1, 20, double.NaN, 4 }, primary);
+        Assert.Equal(new byte[] { 1, 1, 0, 1 }, primaryStatus);
+    }
+
+    [Fact]
+    public void CanWriteJsonLines()
+    {
+        // Arrange
+        var begin = new DateTime(2020, 01, 01, 0, 0, 0, DateTimeKind.Utc);
+        var catalogItem = CreateCatalogItem(NexusDataType.FLOAT64);
+        var values = new double[] { 1.5, 2.5, double.NaN, double.PositiveInfinity };
+        var status = new byte[] { 1, 0, 1, 1 };
+        var request = new ReadRequest(catalogItem, MemoryMarshal.AsBytes(values.AsSpan()).ToArray(), status);
+        using var stream = new MemoryStream();
+
+        // Act
+        ExtensibilityUtilities.WriteJsonLines(stream, begin, request);
+
+        // Assert
+        var lines = Encoding.UTF8
+            .GetString(stream.ToArray())
+            .Split('\n', StringSplitOptions.RemoveEmptyEntries);
+
+        Assert.Equal(4, lines.Length);
+
+        var documents = lines
+            .Select(line => JsonDocument.Parse(line).RootElement)
+            .ToList();
+
+        Assert.Equal(begin.AddSeconds(1), documents[1].GetProperty("t").GetDateTime().ToUniversalTime());
+        Assert.Equal(1.5, documents[0].GetProperty("v").GetDouble());
+        Assert.Equal(JsonValueKind.Null, documents[1].GetProperty("v").ValueKind);
+        Assert.Equal(JsonValueKind.Null, documents[2].GetProperty("v").ValueKind);
+        Assert.Equal(JsonValueKind.Null, documents[3].GetProperty("v").ValueKind);
+    }
+
+    [Fact]
+    public void CanGetSummaryStats()
+    {
+        // Arrange
+        var values = new double[] { 2, double.NaN, -1, 5, double.NaN, 2 };
+
+        // Act
+        var actual = ExtensibilityUtilities.GetSummaryStats(values);
+
+        // Assert
+        Assert.Equal(new SummaryStats(Min: -1, Max: 5, Mean: 2, ValidCount: 4, NanCount: 2), actual);
+    }
+
+    [Fact]
+    public void SummaryStatsOfAllNaNAreNaN()
+    {
+        // Arrange
+        var values = new double[] { double.NaN, double.NaN, double.NaN };
+
+        // Act
+        var actual = ExtensibilityUtilities.GetSummaryStats(values);
+
+        // Assert
+        Assert.True(double.IsNaN(actual.Min));
+        Assert.True(double.IsNaN(actual.Max));
+        Assert.True(double.IsNaN(actual.Mean));
+        Assert.Equal(0, actual.ValidCount);
+        Assert.Equal(3, actual.NanCount);
+    }
+
+    [Fact]
+    public void CanConvertBufferFromInt16ToFloat64()
+    {
+        // Arrange
+        var source = MemoryMarshal.AsBytes(new short[] { -2, 0, 300 }.AsSpan()).ToArray();
+
+        // Act
+        var actual = ExtensibilityUtilities.ConvertBuffer(source, NexusDataType.INT16, NexusDataType.FLOAT64);
+
+        // Assert
+        Assert.Equal(new double[] { -2, 0, 300 }, MemoryMarshal.Cast<byte, double>(actual).ToArray());
+    }
+
+    [Fact]
+    public void CanConvertBufferFromFloat64ToUInt8WithClamping()
+    {
+        // Arrange
+        var source = MemoryMarshal.AsBytes(new double[] { -5.0, 42.0, 1000.0 }.AsSpan()).ToArray();
+
+        // Act
+        var actual = ExtensibilityUtilities.ConvertBuffer(source, NexusDataType.FLOAT64, NexusDataType.UINT8);
+
+        // Assert
+        Assert.Equal(new byte[] { 0, 42, 255 }, actual);
+    }
+
+    [Fact]
+    public void ConvertBufferThrowsForPartialElement()
+    {
+        // Act
+        void action() => ExtensibilityUtilities.ConvertBuffer(new byte[3], NexusDataType.INT16, NexusDataType.FLOAT64);
+
+        // Assert
+        Assert.Throws<ArgumentException>(action);
+    }
+
+    [Fact]
+    public void CanReportElementsAcrossChunks()
+    {
+        // Arrange
+        var progress = new RecordingElementProgress();
+        var total = 250L;
+        var chunkSize = 100L;
+
+        // Act
+        for (var done = chunkSize; done < total + chunkSize; done += chunkSize)
+        {
+            ExtensibilityUtilities.ReportElements(progress, Math.Min(done, total), total);
+        }
+
+        // Assert
+        Assert.Equal(new (long, long)[] { (100, 250), (200, 250), (250, 250) }, progress.Elements);
+        Assert.Empty(progress.Fractions);
+    }
+
+    [Fact]
+    public void ReportElementsFallsBackToFraction()
+    {
+        // Arrange
+        var progress = new RecordingProgress();
+
+        // Act
+        ExtensibilityUtilities.ReportElements(progress, 50, 200);
+        ExtensibilityUtilities.ReportElements(progress, 200, 200);
+
+        // Assert
+        Assert.Equal(new double[] { 0.25, 1.0 }, progress.Fractions);
+    }
+
+    [Fact]
+    public void CanUpgradeSourceConfigurationSequentially()
+    {
+        // Arrange
+        var configuration = new Dictionary<string, JsonElement>()
+        {
+            ["host"] = JsonSerializer.SerializeToElement("localhost")
+        };
+
+        var versionedConfiguration = new MigratingConfiguration();
+
+        // Act
+        var actual = ExtensibilityUtilities.UpgradeSourceConfiguration(versionedConfiguration, configuration);
+
+        // Assert
+        Assert.Equal(new int[] { 1, 2 }, versionedConfiguration.MigratedVersions);
+        Assert.False(actual.ContainsKey("host"));
+        Assert.Equal("localhost", actual["server"].GetString());
+        Assert.Equal(8080, actual["port"].GetInt32());
+        Assert.Equal(3, actual[ExtensibilityUtilities.ConfigurationVersionKey].GetInt32());
+    }
+
+    [Theory]
+    [InlineData("4")]
+    [InlineData("0")]
+    [InlineData("-1")]
+    [InlineData("1.5")]
+    [InlineData("\"2\"")]
+    public void UpgradeSourceConfigurationThrowsForInvalidVersion(string versionJson)
+    {
+        // Arrange
+        var configuration = new Dictionary<string, JsonElement>()
+        {
+            [ExtensibilityUtilities.ConfigurationVersionKey] = JsonSerializer.Deserialize<JsonElement>(versionJson)
+        };
+
+        // Act
+        void action() => ExtensibilityUtilities.UpgradeSourceConfiguration(new MigratingConfiguration(), configuration);
+
+        // Assert
+        Assert.Throws<Exception>(action);
+    }
+
+    private static CatalogItem CreateCatalogItem(NexusDataType dataType, TimeSpan samplePeriod = default)
+    {
+        if (samplePeriod == default)
+            samplePeriod = TimeSpan.FromSeconds(1);
+
+        var representation = new Representation(dataType, samplePeriod);
+        var resource = new Resource(id: "Resource1", representations: [representation]);
+        var catalog = new ResourceCatalog(id: "/A/B/C", resources: [resource]);
+
+        return new CatalogItem(catalog, resource, representation, Parameters: default);
+    }
+
+    private class RecordingProgress : IProgress<double>
+    {
+        public List<double> Fractions { get; } = [];
+
+        public void Report(double value)
+        {
+            Fractions.Add(value);
+        }
+    }
+
+    private class RecordingElementProgress : RecordingProgress, IElementProgress
+    {
+        public List<(long, long)> Elements { get; } = [];
+
+        public void ReportElements(long done, long total)
+        {
+            Elements.Add((done, total));
+        }
+    }
+
+    /* v1: host, v2: host renamed to server, v3: port added */
+    private class MigratingConfiguration : IVersionedConfiguration
+    {
+        public List<int> MigratedVersions { get; } = [];
+
+        public int CurrentVersion => 3;
+
+        public IReadOnlyDictionary<string, JsonElement> Migrate(int fromVersion, IReadOnlyDictionary<string, JsonElement> configuration)
+        {
+            MigratedVersions.Add(fromVersion);
+
+            var migrated = configuration.ToDictionary(entry => entry.Key, entry => entry.Value);
+
+            switch (fromVersion)
+            {
+                case 1:
+                    migrated["server"] = migrated["host"];
+                    migrated.Remove("host");
+                    break;
+
+                case 2:
+                    migrated["port"] = JsonSerializer.SerializeToElement(8080);
+                    break;
+
+                default:
+                    throw new NotSupportedException();
+            }
+
+            return migrated;
+        }
+    }
+
+    /* provides a memory of maximum length without allocating it */
+    private class FakeMemoryManager : MemoryManager<byte>
+    {
+        public override Memory<byte> Memory => CreateMemory(int.MaxValue);
+
+        public override Span<byte> GetSpan() => throw new NotSupportedException();
+
+        public override MemoryHandle Pin(int elementIndex = 0) => throw new NotSupportedException();
+
+        public override void Unpin() => throw new NotSupportedException();
+
+        protected override void Dispose(bool disposing)
+        {
+            //
+        }
+    }
+}
@@ -0,0 +1,37 @@
+// MIT License
+// Copyright (c) [2024] [nexus-main]
+
+using Microsoft.Extensions.Logging;
+using Moq;
+using Xunit;
+
+namespace Nexus.Extensibility.Tests;
+
+public class LoggingExtensionsTests
+{
+    [Fact]
+    public void CanLogWithFields()
+    {
+        // Arrange
+        var logger = Mock.Of<ILogger>();
+
+        var fields = new Dictionary<string, object?>()
+        {
+            ["CatalogId"] = "/A/B/C",
+            ["ElementCount"] = 10
+        };
+
+        // Act
+        logger.LogWithFields(LogLevel.Warning, "Hello", fields);
+
+        // Assert
+        Mock.Get(logger).Verify(current => current.BeginScope<IReadOnlyDictionary<string, object?>>(fields), Times.Once);
+
+        Mock.Get(logger).Verify(current => current.Log(
+            LogLevel.Warning,
+            It.IsAny<EventId>(),
+            It.Is<It.IsAnyType>((state, _) => state.ToString() == "Hello"),
+            It.IsAny<Exception?>(),
+            It.IsAny<Func<It.IsAnyType, Exception?, string>>()), Times.Once);
+    }
+}